@@ -1,15 +1,66 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn;
+use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(HelloProcMacro)]
+use hello_proc_macro_derive_internals::{expand_remote, impl_hello_proc_macro};
+
+#[proc_macro_derive(HelloProcMacro, attributes(hello))]
 pub fn hello_proc_macro_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
-    let ast = syn::parse(input).unwrap;
+    let ast = parse_macro_input!(input as DeriveInput);
 
-    // Build the trait implementation
+    // Build the trait implementation, reporting any problem as a
+    // `compile_error!` pointed at the offending tokens
     impl_hello_proc_macro(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `HelloProcMacro` for a type that can't carry the derive, such
+/// as one generated by another macro. Takes the attributes the derive takes,
+/// followed by either a mirror of the type's definition or its path:
+///
+/// ```
+/// use hello_proc_macro::HelloProcMacro;
+/// use hello_proc_macro_derive::hello_remote;
+///
+/// # #[allow(dead_code)]
+/// struct Mountain {
+///     height: u32,
+/// }
+///
+/// mod geo {
+///     pub struct River;
+/// }
+///
+/// hello_remote! {
+///     #[hello(greeting = "Welcome to {name}")]
+///     struct Mountain {
+///         height: u32,
+///     }
+/// }
+///
+/// hello_remote!(#[hello(name = "full")] geo::River);
+///
+/// assert_eq!(Mountain::GREETING, "Welcome to Mountain");
+/// assert_eq!(Mountain::FIELD_NAMES, ["height"]);
+/// assert_eq!(geo::River::TYPE_NAME, "River");
+/// assert!(geo::River::FULL_TYPE_NAME.ends_with("geo::River"));
+/// ```
+///
+/// A mirror describes the type of the same name in scope and isn't emitted
+/// itself; the implementation is exactly the one the derive would generate
+/// for it, module path included: a mirror can't tell where the type is
+/// declared, so it takes the module it is written in. A path alone gives the
+/// same names and greeting, with an opaque `type_info`, and its prefix gives
+/// the module of the type, resolved from the module the macro is used in.
+/// Orphan rules still apply, so the type has to be declared in the crate
+/// using the macro.
+#[proc_macro]
+pub fn hello_remote(input: TokenStream) -> TokenStream {
+    expand_remote(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /*
@@ -40,15 +91,3 @@ a TokenStream . The returned TokenStream is added to the code that our crate use
 so when they compile their crate, they’ll get the extra functionality that we provide in the
 modified TokenStream.
 */
-
-fn impl_hello_proc_macro(ast: &syn::DeriveInput) {
-    let name = &ast.ident;
-    let gen = quote!{
-        impl HelloProcMacro for name {
-            fn hello_proc_macro() {
-                println!("Hello, the name of your type is {}", stringify!(#name))
-            }
-        }
-    };
-    gen.into()
-}
@@ -0,0 +1,99 @@
+use core::fmt::{self, Write};
+
+use hello_proc_macro::{DynHello, HelloProcMacro};
+use hello_proc_macro_derive::HelloProcMacro;
+
+/// A fixed-size buffer, standing in for what firmware would write to.
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Buffer { bytes: [0; 64], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+#[derive(HelloProcMacro)]
+struct Mountain;
+
+#[derive(HelloProcMacro)]
+enum Direction {
+    North,
+    #[hello(greeting = "Heading {variant}")]
+    South,
+}
+
+#[derive(HelloProcMacro)]
+enum Never {}
+
+struct Lake;
+
+#[derive(HelloProcMacro)]
+#[hello(tree)]
+#[allow(dead_code)]
+struct Range {
+    peak: Mountain,
+    height: u32,
+}
+
+hello_proc_macro::impl_hello!(Lake);
+
+#[derive(HelloProcMacro)]
+#[hello(values, greeting = "Sensor")]
+struct Sensor {
+    id: u8,
+    #[hello(redact)]
+    #[allow(dead_code)]
+    key: u32,
+}
+
+fn main() {
+    let mut out = Buffer::new();
+    Mountain::write_greeting(&mut out).unwrap();
+    assert_eq!(out.as_str(), "Hello, the name of your type is Mountain");
+
+    let mut out = Buffer::new();
+    Direction::North.write_hello(&mut out).unwrap();
+    assert_eq!(out.as_str(), "Hello, this is Direction::North");
+
+    let mut out = Buffer::new();
+    Direction::South.write_hello(&mut out).unwrap();
+    assert_eq!(out.as_str(), "Heading South");
+
+    let mut out = Buffer::new();
+    Mountain.write_hello(&mut out).unwrap();
+    assert_eq!(out.as_str(), Mountain::GREETING);
+
+    assert_eq!(Never::TYPE_NAME, "Never");
+
+    let mut out = Buffer::new();
+    Lake.write_hello(&mut out).unwrap();
+    assert_eq!(out.as_str(), "Hello, the name of your type is Lake");
+
+    let mut out = Buffer::new();
+    Range::write_tree(&mut out).unwrap();
+    assert_eq!(out.as_str(), "Range\n  peak: Mountain\n  height: u32\n");
+
+    let mut out = Buffer::new();
+    Sensor { id: 3, key: 42 }.write_hello(&mut out).unwrap();
+    assert_eq!(out.as_str(), "Sensor { id: 3, key: *** }");
+
+    let value: &dyn DynHello = &Mountain;
+    assert_eq!(value.dyn_type_name(), "Mountain");
+    assert!(value.dyn_full_type_name().ends_with("::Mountain"));
+}
@@ -0,0 +1,15 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(crate = "not a path")]
+struct NotAPath;
+
+#[derive(HelloProcMacro)]
+#[hello(crate = hello_proc_macro)]
+struct NotAString;
+
+#[derive(HelloProcMacro)]
+#[hello(crate = "hello_proc_macro", crate = "hello_proc_macro")]
+struct Duplicate;
+
+fn main() {}
@@ -0,0 +1,18 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+struct Unlogged {
+    #[hello(redact)]
+    password: String,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(values)]
+struct Config {
+    #[hello(skip, redact)]
+    password: String,
+    #[hello(redact, redact)]
+    token: String,
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Welcome aboard, {captain}!")]
+struct UnknownPlaceholder;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Welcome aboard, {name")]
+struct Unclosed;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Welcome aboard, name}")]
+struct Unmatched;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = 42)]
+struct NotAString;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Hi, {name}")]
+#[hello(greeting = "Bye, {name}")]
+struct Duplicate;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "{name} has {fields}")]
+enum NoFields {
+    North,
+    South,
+}
+
+fn main() {}
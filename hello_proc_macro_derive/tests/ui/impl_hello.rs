@@ -0,0 +1,7 @@
+use hello_proc_macro::impl_hello;
+
+pub struct Lake;
+
+impl_hello!(&'static Lake);
+
+fn main() {}
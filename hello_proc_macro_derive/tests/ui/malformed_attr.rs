@@ -0,0 +1,11 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello]
+struct Bare;
+
+#[derive(HelloProcMacro)]
+#[hello = "Mountain"]
+struct NameValue;
+
+fn main() {}
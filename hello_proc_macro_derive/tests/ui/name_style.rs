@@ -0,0 +1,7 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(name = "qualified")]
+struct Mountain;
+
+fn main() {}
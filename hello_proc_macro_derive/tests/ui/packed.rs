@@ -0,0 +1,17 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(values)]
+#[repr(C, packed(2))]
+struct Header {
+    tag: u8,
+    len: u32,
+}
+
+#[derive(HelloProcMacro)]
+#[repr(packed)]
+struct Footer {
+    crc: u32,
+}
+
+fn main() {}
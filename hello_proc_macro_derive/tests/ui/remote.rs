@@ -0,0 +1,21 @@
+use hello_proc_macro_derive::hello_remote;
+
+pub struct Mountain;
+
+hello_remote!(#[hello(shout)] Mountain);
+
+hello_remote!(Mountain River);
+
+hello_remote!(#[hello(values)] Mountain);
+
+hello_remote! {
+    #[hello(greeting = "{fields}")]
+    Mountain
+}
+
+hello_remote! {
+    #[hello(greeting = "A {kind}")]
+    Mountain
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(rename_all = "Title Case")]
+struct Summit {
+    height: u32,
+}
+
+#[derive(HelloProcMacro)]
+enum Weather {
+    #[hello(rename = "clear", rename = "sunny")]
+    Clear,
+    #[hello(rename = 3)]
+    Rain,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(values)]
+union Bits {
+    int: u32,
+    #[hello(redact)]
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(shout)]
+struct Mountain {
+    #[hello(whisper = true)]
+    height: u32,
+}
+
+fn main() {}
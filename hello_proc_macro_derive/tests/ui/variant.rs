@@ -0,0 +1,21 @@
+use hello_proc_macro_derive::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Hello from {variant}")]
+enum TypeLevelVariant {
+    North,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Hello from {variant}")]
+struct NotAnEnum;
+
+#[derive(HelloProcMacro)]
+enum BadVariant {
+    #[hello(greeting = "Hello from {direction}")]
+    North,
+    #[hello(shout)]
+    South,
+}
+
+fn main() {}
@@ -0,0 +1,445 @@
+//! Parsing of the `#[hello(...)]` helper attribute.
+
+use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Data, DeriveInput, Expr, ExprLit, Lit, LitStr, Meta, Token};
+
+use crate::case::RenameRule;
+use crate::serde::Serde;
+use crate::template::Template;
+
+/// Collects errors so that every problem with the input is reported in a
+/// single compile rather than one at a time.
+#[derive(Default)]
+pub struct Errors(Option<syn::Error>);
+
+impl Errors {
+    pub fn push(&mut self, err: syn::Error) {
+        match &mut self.0 {
+            Some(all) => all.combine(err),
+            None => self.0 = Some(err),
+        }
+    }
+
+    pub fn finish(self) -> syn::Result<()> {
+        match self.0 {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Options set with `#[hello(...)]` on the deriving type.
+pub struct Container {
+    /// `#[hello(greeting = "...")]`
+    pub greeting: Option<Template>,
+    /// `#[hello(crate = "...")]`, the path generated code uses to reach the
+    /// `hello_proc_macro` crate.
+    pub krate: Option<syn::Path>,
+    /// `#[hello(name = "short" | "full")]`
+    pub name: NameStyle,
+    /// `#[hello(tree)]`, generating `write_tree_fields`.
+    pub tree: bool,
+    /// `#[hello(values)]`, greeting values along with their fields.
+    pub values: bool,
+    /// `#[hello(rename_all = "...")]`, renaming the fields of a struct or the
+    /// variants of an enum.
+    pub rename_all: Option<RenameRule>,
+    /// `#[hello(serde_compat)]`, reading names and skipped fields from
+    /// `#[serde(...)]` where `#[hello(...)]` doesn't set them.
+    pub serde_compat: bool,
+    /// With `serde_compat`, the `#[serde(rename = "...")]` of the type, which
+    /// names it in greetings instead of its Rust name.
+    pub serde_name: Option<String>,
+    /// The options of each field, in declaration order, if this is a struct
+    /// or a union.
+    pub fields: Vec<Field>,
+    /// The options of each variant, in declaration order, if this is an enum.
+    pub variants: Vec<Variant>,
+}
+
+/// How `{name}` is rendered in greetings.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameStyle {
+    /// `Mountain`
+    #[default]
+    Short,
+    /// `my_crate::geo::Mountain`
+    Full,
+}
+
+/// Options set with `#[hello(...)]` on an enum variant.
+pub struct Variant {
+    /// The name of the variant in everything the derive generates, after
+    /// `#[hello(rename = "...")]` or the `rename_all` of the enum.
+    pub name: String,
+    /// `#[hello(greeting = "...")]`
+    pub greeting: Option<Template>,
+    /// The options of each field of the variant, in declaration order.
+    pub fields: Vec<Field>,
+}
+
+/// Options set with `#[hello(...)]` on a field, of a struct or a variant.
+pub struct Field {
+    /// The name of the field in everything the derive generates, after
+    /// `#[hello(rename = "...")]` or the `rename_all` that applies to it.
+    /// Tuple fields are named by their index.
+    pub name: String,
+    /// Whether the field is in the lists of field names, `{fields}` and
+    /// `FIELD_NAMES`. Only fields serde skips are left out.
+    pub listed: bool,
+    /// `#[hello(skip)]`, leaving the field out of the values.
+    pub skip: bool,
+    /// `#[hello(redact)]`, showing `***` instead of the value.
+    pub redact: bool,
+}
+
+impl Container {
+    pub fn from_ast(ast: &DeriveInput) -> syn::Result<Self> {
+        let mut errors = Errors::default();
+
+        let mut container = Container::parse(&ast.attrs, &mut errors);
+
+        // Nothing tells which field of a union holds the value, so there are
+        // no values to greet.
+        if let (Data::Union(data), true) = (&ast.data, container.values) {
+            errors.push(syn::Error::new_spanned(
+                data.union_token,
+                "`values` can't be used on unions, which don't know which of their fields is active",
+            ));
+        }
+        // The values are written through references, which can't be taken
+        // to the unaligned fields of a packed struct.
+        if let (Some(packed), true) = (packed_repr(&ast.attrs), container.values) {
+            errors.push(syn::Error::new_spanned(
+                packed,
+                "`values` can't be used on `repr(packed)` types, whose fields can't be borrowed",
+            ));
+        }
+        let values = container.values;
+        let serde_compat = container.serde_compat;
+        if serde_compat {
+            let serde = Serde::from_attrs(&ast.attrs);
+            if container.rename_all.is_none() {
+                container.rename_all = serde.rename_all;
+            }
+            container.serde_name = serde.rename;
+        }
+        let rule = container.rename_all;
+
+        match &ast.data {
+            Data::Struct(data) => {
+                container.fields =
+                    Field::from_fields(&data.fields, rule, values, serde_compat, &mut errors);
+            }
+            Data::Enum(data) => {
+                for variant in &data.variants {
+                    container.variants.push(Variant::from_ast(
+                        variant,
+                        rule,
+                        values,
+                        serde_compat,
+                        &mut errors,
+                    ));
+                }
+            }
+            Data::Union(data) => {
+                container.fields = data
+                    .fields
+                    .named
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        Field::from_ast(field, index, rule, values, serde_compat, &mut errors)
+                    })
+                    .collect();
+            }
+        }
+
+        errors.finish()?;
+        Ok(container)
+    }
+
+    /// The options of a type known only by its path, as in
+    /// `hello_remote!(#[hello(...)] path::to::Type)`.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut errors = Errors::default();
+        let container = Container::parse(attrs, &mut errors);
+        errors.finish()?;
+        Ok(container)
+    }
+
+    /// Parses the options of the type itself, leaving `variants` empty.
+    fn parse(attrs: &[Attribute], errors: &mut Errors) -> Self {
+        let mut greeting = None;
+        let mut krate = None;
+        let mut name = None;
+        let mut tree = None;
+        let mut values = None;
+        let mut rename_all = None;
+        let mut serde_compat = None;
+
+        for_each_option(attrs, errors, |meta| {
+            if meta.path.is_ident("greeting") {
+                let template = Template::parse(&lit_str(&meta)?)?;
+                set_once(&mut greeting, &meta, template)
+            } else if meta.path.is_ident("crate") {
+                let lit = lit_str(&meta)?;
+                let path = lit.parse().map_err(|err| {
+                    syn::Error::new(lit.span(), format!("`crate` must be a path: {}", err))
+                })?;
+                set_once(&mut krate, &meta, path)
+            } else if meta.path.is_ident("name") {
+                let lit = lit_str(&meta)?;
+                let style = match lit.value().as_str() {
+                    "short" => NameStyle::Short,
+                    "full" => NameStyle::Full,
+                    _ => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "expected `name` to be \"short\" or \"full\"",
+                        ))
+                    }
+                };
+                set_once(&mut name, &meta, style)
+            } else if meta.path.is_ident("tree") {
+                set_once(&mut tree, &meta, ())
+            } else if meta.path.is_ident("values") {
+                set_once(&mut values, &meta, ())
+            } else if meta.path.is_ident("rename_all") {
+                set_once(&mut rename_all, &meta, rename_rule(&meta)?)
+            } else if meta.path.is_ident("serde_compat") {
+                set_once(&mut serde_compat, &meta, ())
+            } else {
+                Err(unknown_option(&meta))
+            }
+        });
+
+        Container {
+            greeting,
+            krate,
+            name: name.unwrap_or_default(),
+            tree: tree.is_some(),
+            values: values.is_some(),
+            rename_all,
+            serde_compat: serde_compat.is_some(),
+            serde_name: None,
+            fields: Vec::new(),
+            variants: Vec::new(),
+        }
+    }
+}
+
+impl Variant {
+    /// Parses the options of `variant`, where `rule` is the `rename_all` of
+    /// the enum.
+    fn from_ast(
+        variant: &syn::Variant,
+        rule: Option<RenameRule>,
+        values: bool,
+        serde_compat: bool,
+        errors: &mut Errors,
+    ) -> Self {
+        let mut greeting = None;
+        let mut rename = None;
+        let mut rename_all = None;
+
+        for_each_option(&variant.attrs, errors, |meta| {
+            if meta.path.is_ident("greeting") {
+                let template = Template::parse(&lit_str(&meta)?)?;
+                set_once(&mut greeting, &meta, template)
+            } else if meta.path.is_ident("rename") {
+                set_once(&mut rename, &meta, lit_str(&meta)?.value())
+            } else if meta.path.is_ident("rename_all") {
+                set_once(&mut rename_all, &meta, rename_rule(&meta)?)
+            } else {
+                Err(unknown_option(&meta))
+            }
+        });
+
+        // A variant serde skips still exists, so its `skip` is ignored.
+        if serde_compat {
+            let serde = Serde::from_attrs(&variant.attrs);
+            rename = rename.or(serde.rename);
+            rename_all = rename_all.or(serde.rename_all);
+        }
+
+        let name = rename.unwrap_or_else(|| {
+            let name = variant.ident.unraw().to_string();
+            match rule {
+                Some(rule) => rule.apply_to_variant(&name),
+                None => name,
+            }
+        });
+        Variant {
+            name,
+            greeting,
+            fields: Field::from_fields(&variant.fields, rename_all, values, serde_compat, errors),
+        }
+    }
+}
+
+impl Field {
+    fn from_fields(
+        fields: &syn::Fields,
+        rule: Option<RenameRule>,
+        values: bool,
+        serde_compat: bool,
+        errors: &mut Errors,
+    ) -> Vec<Self> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| Field::from_ast(field, index, rule, values, serde_compat, errors))
+            .collect()
+    }
+
+    /// Parses the options of the field at `index`, where `rule` is the
+    /// `rename_all` that applies to it and `values` is whether the type
+    /// greets its values.
+    fn from_ast(
+        field: &syn::Field,
+        index: usize,
+        rule: Option<RenameRule>,
+        values: bool,
+        serde_compat: bool,
+        errors: &mut Errors,
+    ) -> Self {
+        let mut rename = None;
+        let mut skip = None;
+        let mut redact = None;
+
+        for_each_option(&field.attrs, errors, |meta| {
+            if meta.path.is_ident("rename") {
+                return set_once(&mut rename, &meta, lit_str(&meta)?.value());
+            }
+            let slot = if meta.path.is_ident("skip") {
+                &mut skip
+            } else if meta.path.is_ident("redact") {
+                &mut redact
+            } else {
+                return Err(unknown_option(&meta));
+            };
+            if !values {
+                let name = meta.path.to_token_stream();
+                return Err(syn::Error::new_spanned(
+                    &meta.path,
+                    format!(
+                        "`{}` has no effect without `#[hello(values)]` on the type",
+                        name
+                    ),
+                ));
+            }
+            set_once(slot, &meta, meta.path.clone())
+        });
+
+        if let (Some(_), Some(redact)) = (&skip, &redact) {
+            errors.push(syn::Error::new_spanned(
+                redact,
+                "a field can't be both skipped and redacted",
+            ));
+        }
+
+        let serde = if serde_compat {
+            Serde::from_attrs(&field.attrs)
+        } else {
+            Serde::default()
+        };
+
+        let name = rename
+            .or(serde.rename)
+            .unwrap_or_else(|| match &field.ident {
+                Some(ident) => {
+                    let name = ident.unraw().to_string();
+                    match rule {
+                        Some(rule) => rule.apply_to_field(&name),
+                        None => name,
+                    }
+                }
+                None => index.to_string(),
+            });
+        Field {
+            name,
+            listed: !serde.skip,
+            skip: skip.is_some() || serde.skip,
+            redact: redact.is_some(),
+        }
+    }
+}
+
+/// Calls `parse` with every option of every `#[hello(...)]` attribute in
+/// `attrs`. An error ends the attribute it was found in, but the other
+/// attributes are still parsed.
+fn for_each_option(
+    attrs: &[Attribute],
+    errors: &mut Errors,
+    mut parse: impl FnMut(ParseNestedMeta) -> syn::Result<()>,
+) {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("hello")) {
+        if !matches!(attr.meta, Meta::List(_)) {
+            errors.push(syn::Error::new_spanned(
+                &attr.meta,
+                "expected an attribute of the form `#[hello(...)]`",
+            ));
+            continue;
+        }
+        if let Err(err) = attr.parse_nested_meta(&mut parse) {
+            errors.push(err);
+        }
+    }
+}
+
+/// The `packed` or `packed(N)` of a `#[repr(...)]` in `attrs`. A `repr` that
+/// doesn't parse is left to the compiler to report.
+fn packed_repr(attrs: &[Attribute]) -> Option<Meta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .find(|meta| meta.path().is_ident("packed"))
+}
+
+/// The string literal of `name = "..."`.
+fn lit_str(meta: &ParseNestedMeta) -> syn::Result<LitStr> {
+    match meta.value()?.parse()? {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit),
+        expr => {
+            let name = meta.path.to_token_stream();
+            Err(syn::Error::new_spanned(
+                expr,
+                format!("expected `{}` to be a string literal", name),
+            ))
+        }
+    }
+}
+
+/// The case of `rename_all = "..."`.
+fn rename_rule(meta: &ParseNestedMeta) -> syn::Result<RenameRule> {
+    let lit = lit_str(meta)?;
+    RenameRule::from_str(&lit.value()).map_err(|err| syn::Error::new(lit.span(), err))
+}
+
+/// Stores `value` in `slot`, unless the option was already set.
+fn set_once<T>(slot: &mut Option<T>, meta: &ParseNestedMeta, value: T) -> syn::Result<()> {
+    if slot.is_some() {
+        let name = meta.path.to_token_stream();
+        return Err(syn::Error::new_spanned(
+            &meta.path,
+            format!("duplicate `{}` option", name),
+        ));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn unknown_option(meta: &ParseNestedMeta) -> syn::Error {
+    meta.error("unknown `hello` option")
+}
@@ -0,0 +1,39 @@
+//! Bounds added to the generated implementation.
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{Generics, Ident};
+
+/// `generics` with `bound` on every type parameter that `types` mention.
+/// Like other derives, only the parameters are bounded, so that a recursive
+/// type doesn't require itself to implement the trait.
+pub fn with_bound<'a>(
+    generics: &Generics,
+    types: impl Iterator<Item = &'a syn::Type>,
+    bound: &TokenStream,
+) -> Generics {
+    let mut generics = generics.clone();
+    let types: Vec<TokenStream> = types.map(ToTokens::to_token_stream).collect();
+    let used: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .filter(|param| types.iter().any(|ty| mentions(ty.clone(), param)))
+        .collect();
+
+    let where_clause = generics.make_where_clause();
+    for param in used {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: #bound));
+    }
+    generics
+}
+
+/// Whether `tokens` contain the identifier `ident`.
+fn mentions(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(other) => other == *ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
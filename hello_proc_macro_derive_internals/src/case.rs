@@ -0,0 +1,106 @@
+//! The case conversions of `#[hello(rename_all = "...")]`, the same ones
+//! serde offers.
+
+use self::RenameRule::*;
+
+/// A case to rename fields or variants to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+static RULES: &[(&str, RenameRule)] = &[
+    ("lowercase", Lower),
+    ("UPPERCASE", Upper),
+    ("PascalCase", Pascal),
+    ("camelCase", Camel),
+    ("snake_case", Snake),
+    ("SCREAMING_SNAKE_CASE", ScreamingSnake),
+    ("kebab-case", Kebab),
+    ("SCREAMING-KEBAB-CASE", ScreamingKebab),
+];
+
+impl RenameRule {
+    /// The rule called `name`, or an error listing the rules there are.
+    pub fn from_str(name: &str) -> Result<Self, String> {
+        RULES
+            .iter()
+            .find(|(rule_name, _)| *rule_name == name)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let names: Vec<String> = RULES
+                    .iter()
+                    .map(|(rule_name, _)| format!("\"{}\"", rule_name))
+                    .collect();
+                format!(
+                    "unknown case \"{}\", expected one of {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+
+    /// Renames a variant, written in `Pascal`.
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Pascal => variant.to_owned(),
+            Lower => variant.to_ascii_lowercase(),
+            Upper => variant.to_ascii_uppercase(),
+            Camel => lowercase_first(variant),
+            Snake => {
+                let mut snake = String::new();
+                for (index, ch) in variant.char_indices() {
+                    if index > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            ScreamingSnake => Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Kebab => Snake.apply_to_variant(variant).replace('_', "-"),
+            ScreamingKebab => ScreamingSnake.apply_to_variant(variant).replace('_', "-"),
+        }
+    }
+
+    /// Renames a field, written in `snake_case`.
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Lower | Snake => field.to_owned(),
+            Upper | ScreamingSnake => field.to_ascii_uppercase(),
+            Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Camel => lowercase_first(&Pascal.apply_to_field(field)),
+            Kebab => field.replace('_', "-"),
+            ScreamingKebab => ScreamingSnake.apply_to_field(field).replace('_', "-"),
+        }
+    }
+}
+
+fn lowercase_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,289 @@
+//! The expansion behind `#[derive(HelloProcMacro)]`.
+//!
+//! `hello_proc_macro_derive` is only a thin wrapper around this crate, which
+//! works on `proc_macro2` token streams so that it can run outside of the
+//! compiler: in tests, or from another crate's `build.rs`.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::{Data, DataEnum, DeriveInput, Fields};
+
+mod attr;
+mod bound;
+mod case;
+mod remote;
+mod render;
+mod serde;
+mod template;
+mod tree;
+mod type_info;
+mod values;
+
+use crate::template::{Context, Template};
+
+/// Expands `#[derive(HelloProcMacro)]` on `input`, which must be a struct,
+/// enum or union, to the trait implementation.
+pub fn expand_derive(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2(input)?;
+    impl_hello_proc_macro(&ast)
+}
+
+/// Expands `hello_remote!(input)`, where `input` is either a mirror of the
+/// definition of a type in scope or the path of a type, preceded by the
+/// attributes the derive takes. A mirror expands exactly like the derive.
+pub fn expand_remote(input: TokenStream) -> syn::Result<TokenStream> {
+    match syn::parse2(input)? {
+        remote::Remote::Mirror(ast) => impl_hello_proc_macro(&ast),
+        remote::Remote::Path { attrs, path } => remote::expand_path(&attrs, &path),
+    }
+}
+
+/// Builds the trait implementation for an already parsed `ast`.
+pub fn impl_hello_proc_macro(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let mut attrs = attr::Container::from_ast(ast)?;
+    let krate = attrs
+        .krate
+        .take()
+        .unwrap_or_else(|| syn::parse_quote!(::hello_proc_macro));
+
+    let name = &ast.ident;
+    let type_name = format!("{}{}", name.unraw(), render::render_generics(&ast.generics));
+    let (kind, fields) = match &ast.data {
+        Data::Struct(_) => ("struct", Some(listed_names(&attrs.fields).join(", "))),
+        Data::Enum(_) => ("enum", None),
+        Data::Union(_) => ("union", Some(listed_names(&attrs.fields).join(", "))),
+    };
+    let full_name = attrs.name == attr::NameStyle::Full;
+    // Greetings name the type the way serde does, with `serde_compat`.
+    let greeting_name = attrs
+        .serde_name
+        .clone()
+        .unwrap_or_else(|| type_name.clone());
+    let greeting = attrs.greeting.take().unwrap_or_default().expand(&Context {
+        name: &greeting_name,
+        full_name,
+        module: &quote!(::core::module_path!()),
+        kind: Some(kind),
+        fields: fields.as_deref(),
+        variant: None,
+    })?;
+    let field_consts = match &ast.data {
+        Data::Struct(data) => Some(impl_field_consts(&data.fields, &attrs.fields)),
+        Data::Union(data) => Some(impl_field_consts(
+            &Fields::Named(data.fields.clone()),
+            &attrs.fields,
+        )),
+        Data::Enum(_) => None,
+    };
+    let type_info = type_info::expand(ast, &attrs, &krate)?;
+    let mut generics = ast.generics.clone();
+    let tree = if attrs.tree {
+        generics = tree::with_bounds(&generics, &ast.data, &krate);
+        tree::expand(ast, &attrs, &krate)
+    } else {
+        None
+    };
+    if attrs.values {
+        generics = values::with_bounds(&generics, &ast.data, &attrs);
+    }
+    let hello = match &ast.data {
+        Data::Enum(data) => Some(impl_hello_for_enum(
+            ast,
+            data,
+            &attrs,
+            &greeting_name,
+            full_name,
+            &krate,
+        )?),
+        Data::Struct(data) if attrs.values => {
+            Some(values::impl_for_struct(&data.fields, &attrs.fields, &krate))
+        }
+        // Unions keep the default `hello`, which greets the type without
+        // reading a field that may not be the active one.
+        _ => None,
+    };
+
+    // Generic types are left out of the registry, since there's no single
+    // type to register.
+    let registration = if ast.generics.params.is_empty() {
+        Some(impl_registration(&ast.ident, &krate))
+    } else {
+        None
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics #krate::HelloProcMacro for #name #ty_generics #where_clause {
+            const TYPE_NAME: &'static str = #type_name;
+            const MODULE_PATH: &'static str = ::core::module_path!();
+            const FULL_TYPE_NAME: &'static str =
+                ::core::concat!(::core::module_path!(), "::", #type_name);
+            const GREETING: &'static str = #greeting;
+            #field_consts
+
+            #type_info
+
+            #tree
+
+            #hello
+        }
+
+        #registration
+    };
+    Ok(gen)
+}
+
+/// Adds `ty` to the registry behind the `registry` feature.
+fn impl_registration(ty: &impl ToTokens, krate: &syn::Path) -> TokenStream {
+    quote! {
+        #krate::__private::if_registry! {
+            const _: () = {
+                #[#krate::__private::linkme::distributed_slice(#krate::__private::REGISTRY)]
+                #[linkme(crate = #krate::__private::linkme)]
+                static REGISTRATION: #krate::registry::Registration =
+                    #krate::registry::Registration::of::<#ty>();
+            };
+        }
+    }
+}
+
+/// Builds the `FIELD_*` constants describing the fields of a struct or union.
+fn impl_field_consts(fields: &Fields, attrs: &[attr::Field]) -> TokenStream {
+    let names = listed_names(attrs);
+    let types = fields
+        .iter()
+        .zip(attrs)
+        .filter(|(_, attrs)| attrs.listed)
+        .map(|(field, _)| render::render_type(&field.ty));
+
+    quote! {
+        const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+        const FIELD_TYPES: &'static [&'static str] = &[#(#types),*];
+    }
+}
+
+/// Builds `hello(&self)` for an enum, greeting whichever variant is active,
+/// followed by its values with `#[hello(values)]`.
+fn impl_hello_for_enum(
+    ast: &DeriveInput,
+    data: &DataEnum,
+    attrs: &attr::Container,
+    greeting_name: &str,
+    full_name: bool,
+    krate: &syn::Path,
+) -> syn::Result<TokenStream> {
+    let enum_name = match &attrs.serde_name {
+        Some(name) => name.clone(),
+        None => ast.ident.unraw().to_string(),
+    };
+    let mut arms = Vec::new();
+    for (variant, variant_attrs) in data.variants.iter().zip(&attrs.variants) {
+        let ident = &variant.ident;
+        let variant_name = &variant_attrs.name;
+        let context = Context {
+            name: greeting_name,
+            full_name,
+            module: &quote!(::core::module_path!()),
+            kind: Some("enum"),
+            fields: Some(&listed_names(&variant_attrs.fields).join(", ")),
+            variant: Some(variant_name),
+        };
+        let greeting = match &variant_attrs.greeting {
+            Some(template) => template.expand(&context)?,
+            None => Template::default_for_variant(&enum_name, variant_name, full_name)
+                .expand(&context)?,
+        };
+
+        if !attrs.values {
+            arms.push(quote! {
+                Self::#ident { .. } => #greeting,
+            });
+            continue;
+        }
+        let visible = values::visible(&variant.fields, &variant_attrs.fields);
+        let bindings = visible
+            .iter()
+            .filter(|value| !value.attrs.redact)
+            .map(|value| {
+                let member = &value.member;
+                let binding = &value.binding;
+                quote!(#member: ref #binding,)
+            });
+        let values = values::write(&visible, |value| {
+            let binding = &value.binding;
+            quote!(#binding)
+        });
+        arms.push(quote! {
+            Self::#ident { #(#bindings)* .. } => {
+                out.write_str(#greeting)?;
+                #values
+            }
+        });
+    }
+
+    // An empty enum has no value to greet.
+    if data.variants.is_empty() {
+        return Ok(quote! {
+            fn write_hello<__HelloWrite: ::core::fmt::Write + ?::core::marker::Sized>(
+                &self,
+                _: &mut __HelloWrite,
+            ) -> ::core::fmt::Result {
+                match *self {}
+            }
+        });
+    }
+
+    if attrs.values {
+        let hello = values::impl_hello(krate);
+        return Ok(quote! {
+            fn write_hello<__HelloWrite: ::core::fmt::Write + ?::core::marker::Sized>(
+                &self,
+                out: &mut __HelloWrite,
+            ) -> ::core::fmt::Result {
+                match *self {
+                    #(#arms)*
+                }
+                ::core::result::Result::Ok(())
+            }
+
+            #hello
+        });
+    }
+
+    // Only `hello` needs `alloc`, `write_hello` has to work without it.
+    Ok(quote! {
+        fn write_hello<__HelloWrite: ::core::fmt::Write + ?::core::marker::Sized>(
+            &self,
+            out: &mut __HelloWrite,
+        ) -> ::core::fmt::Result {
+            out.write_str(match *self {
+                #(#arms)*
+            })
+        }
+
+        #krate::__private::if_alloc! {
+            fn hello(&self) -> #krate::__private::Cow<'static, str> {
+                #krate::__private::Cow::Borrowed(match *self {
+                    #(#arms)*
+                })
+            }
+        }
+    })
+}
+
+/// The names of fields as the derive writes them, renamed and with tuple
+/// fields named by their index.
+fn field_names(fields: &[attr::Field]) -> Vec<&str> {
+    fields.iter().map(|field| field.name.as_str()).collect()
+}
+
+/// The names of the fields in lists of field names, leaving out the ones
+/// serde skips.
+fn listed_names(fields: &[attr::Field]) -> Vec<&str> {
+    fields
+        .iter()
+        .filter(|field| field.listed)
+        .map(|field| field.name.as_str())
+        .collect()
+}
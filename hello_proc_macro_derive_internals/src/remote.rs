@@ -0,0 +1,128 @@
+//! The input of `hello_remote!`, for types that can't carry the derive.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, DeriveInput, Token};
+
+use crate::attr;
+use crate::render;
+use crate::template::Context;
+
+/// Either a mirror of the type's definition or just its path, both preceded
+/// by the same `#[hello(...)]` attributes the derive takes.
+pub enum Remote {
+    /// `struct Mountain { ... }`, describing a type of the same name that is
+    /// in scope. The mirror itself is not emitted.
+    Mirror(DeriveInput),
+    /// `path::to::Mountain`, for a type whose shape doesn't matter.
+    Path {
+        attrs: Vec<Attribute>,
+        path: syn::Path,
+    },
+}
+
+impl Parse for Remote {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+
+        let lookahead = input.lookahead1();
+        let remote = if lookahead.peek(Token![struct])
+            || lookahead.peek(Token![enum])
+            || lookahead.peek(Token![union])
+            || lookahead.peek(Token![pub])
+        {
+            let mut ast: DeriveInput = input.parse()?;
+            ast.attrs = attrs;
+            Remote::Mirror(ast)
+        } else if lookahead.peek(Token![::]) || lookahead.peek(syn::Ident::peek_any) {
+            let path = input.parse()?;
+            input.parse::<Option<Token![;]>>()?;
+            Remote::Path { attrs, path }
+        } else {
+            return Err(lookahead.error());
+        };
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the type"));
+        }
+        Ok(remote)
+    }
+}
+
+/// Builds the trait implementation for a type known only by its path: the
+/// derive's implementation minus everything that describes the fields and
+/// variants, which leaves `type_info` opaque.
+pub fn expand_path(attrs: &[Attribute], path: &syn::Path) -> syn::Result<TokenStream> {
+    let attrs = attr::Container::from_attrs(attrs)?;
+    // Options that would do nothing without the definition are rejected,
+    // like `redact` without `values` in the derive.
+    let needs_definition = [
+        ("tree", attrs.tree, "fields"),
+        ("values", attrs.values, "fields"),
+        (
+            "rename_all",
+            attrs.rename_all.is_some(),
+            "fields and variants",
+        ),
+        ("serde_compat", attrs.serde_compat, "fields and variants"),
+    ];
+    for (option, set, needs) in needs_definition {
+        if set {
+            return Err(syn::Error::new_spanned(
+                path,
+                format!(
+                    "`{}` needs the {} of the type, pass a mirror of its definition instead",
+                    option, needs
+                ),
+            ));
+        }
+    }
+    let krate = attrs
+        .krate
+        .unwrap_or_else(|| syn::parse_quote!(::hello_proc_macro));
+
+    let last = path
+        .segments
+        .last()
+        .ok_or_else(|| syn::Error::new_spanned(path, "expected a type"))?;
+    // Named like the derive would, so `River::<u8>` is `River<u8>` and
+    // `r#Match` is `Match`.
+    let arguments = match &last.arguments {
+        syn::PathArguments::AngleBracketed(arguments) => format!(
+            "<{}>",
+            render::render_tokens(arguments.args.to_token_stream())
+        ),
+        arguments => render::render_tokens(arguments.to_token_stream()),
+    };
+    let type_name = format!("{}{}", last.ident.unraw(), arguments);
+    // The type lives in the module its path names, resolved from the module
+    // the macro is used in, which only `module_path!()` knows.
+    let rendered_path = render::render_tokens(path.to_token_stream());
+    let module = quote!(#krate::__private::module_path!(#rendered_path));
+    let greeting = attrs.greeting.unwrap_or_default().expand_joined(
+        &Context {
+            name: &type_name,
+            full_name: attrs.name == attr::NameStyle::Full,
+            module: &module,
+            kind: None,
+            fields: None,
+            variant: None,
+        },
+        &krate,
+    )?;
+    let registration = crate::impl_registration(path, &krate);
+
+    Ok(quote! {
+        impl #krate::HelloProcMacro for #path {
+            const TYPE_NAME: &'static str = #type_name;
+            const MODULE_PATH: &'static str = #module;
+            const FULL_TYPE_NAME: &'static str =
+                #krate::__private::full_type_name!(#rendered_path);
+            const GREETING: &'static str = #greeting;
+        }
+
+        #registration
+    })
+}
@@ -0,0 +1,161 @@
+//! Rendering of type names the way they were declared.
+
+use proc_macro2::{Delimiter, Spacing, TokenTree};
+use quote::ToTokens;
+
+/// Renders the generic parameter list of a type the way it was declared,
+/// bounds and defaults included, e.g. `<'a, T: Clone, const N: usize = 3>`.
+///
+/// `stringify!` puts a space between every token, which is not what anyone
+/// wants to read in a greeting, so the tokens are laid out here instead.
+pub fn render_generics(generics: &syn::Generics) -> String {
+    if generics.params.is_empty() {
+        return String::new();
+    }
+    format!("<{}>", render_tokens(generics.params.to_token_stream()))
+}
+
+/// Renders a type the way it was declared, e.g. `Vec<&'a str>`.
+pub fn render_type(ty: &syn::Type) -> String {
+    render_tokens(ty.to_token_stream())
+}
+
+/// A piece of rendered source, along with whether it wants a space on
+/// either side of it.
+struct Atom {
+    text: String,
+    space_before: bool,
+    space_after: bool,
+    /// Whether a parenthesized group right after this atom belongs to it,
+    /// as the arguments of `Fn(u8)` do.
+    takes_args: bool,
+}
+
+/// Renders `tokens` with the spacing rustfmt would give them.
+pub fn render_tokens(tokens: proc_macro2::TokenStream) -> String {
+    let mut atoms: Vec<Atom> = Vec::new();
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        let atom = match token {
+            TokenTree::Ident(ident) => {
+                let text = ident.to_string();
+                // Neither a lifetime nor a keyword like the `mut` of
+                // `&mut (u8, u16)` is followed by arguments.
+                let is_lifetime = atoms.last().is_some_and(|prev| prev.text == "'");
+                let takes_args = !is_lifetime && !is_keyword(&text);
+                Atom {
+                    text,
+                    space_before: true,
+                    space_after: true,
+                    takes_args,
+                }
+            }
+            TokenTree::Literal(literal) => Atom {
+                text: literal.to_string(),
+                space_before: true,
+                space_after: true,
+                takes_args: false,
+            },
+            TokenTree::Group(group) => {
+                let inner = render_tokens(group.stream());
+                let text = match group.delimiter() {
+                    Delimiter::Parenthesis => format!("({})", inner),
+                    Delimiter::Bracket => format!("[{}]", inner),
+                    Delimiter::Brace => format!("{{ {} }}", inner),
+                    Delimiter::None => inner,
+                };
+                // `Fn(u8)` hugs its parentheses, a tuple after `=` and a
+                // slice after `&'a` do not.
+                let is_args = group.delimiter() == Delimiter::Parenthesis
+                    && atoms.last().is_some_and(|prev| prev.takes_args);
+                Atom {
+                    text,
+                    space_before: !is_args,
+                    space_after: true,
+                    takes_args: false,
+                }
+            }
+            TokenTree::Punct(punct) => {
+                let ch = punct.as_char();
+                let joined = match tokens.peek() {
+                    Some(TokenTree::Punct(next)) if punct.spacing() == Spacing::Joint => {
+                        // `>>` is left alone, it closes nested generics far
+                        // more often than it shifts.
+                        match (ch, next.as_char()) {
+                            (':', ':') => Some("::"),
+                            ('-', '>') => Some("->"),
+                            ('=', '>') => Some("=>"),
+                            ('<', '<') => Some("<<"),
+                            ('<', '=') => Some("<="),
+                            ('>', '=') => Some(">="),
+                            ('=', '=') => Some("=="),
+                            ('!', '=') => Some("!="),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                match joined {
+                    Some(op) => {
+                        tokens.next();
+                        let spaced = op != "::";
+                        Atom {
+                            text: op.to_owned(),
+                            space_before: spaced,
+                            space_after: spaced,
+                            takes_args: false,
+                        }
+                    }
+                    None => {
+                        let (space_before, space_after) = match ch {
+                            ',' | ';' | ':' => (false, true),
+                            '<' | '.' => (false, false),
+                            '>' => (false, true),
+                            '&' | '*' | '?' | '!' | '\'' => (true, false),
+                            // A minus sign that doesn't follow an operand is
+                            // a negation, as in `= -1`.
+                            '-' if !atoms.last().is_some_and(is_operand) => (true, false),
+                            _ => (true, true),
+                        };
+                        Atom {
+                            text: ch.to_string(),
+                            space_before,
+                            space_after,
+                            takes_args: false,
+                        }
+                    }
+                }
+            }
+        };
+        atoms.push(atom);
+    }
+
+    let mut rendered = String::new();
+    let mut prev: Option<&Atom> = None;
+    for atom in &atoms {
+        if let Some(prev) = prev {
+            if prev.space_after && atom.space_before {
+                rendered.push(' ');
+            }
+        }
+        rendered.push_str(&atom.text);
+        prev = Some(atom);
+    }
+    rendered
+}
+
+/// Whether `atom` can be the left-hand side of a binary operator.
+fn is_operand(atom: &Atom) -> bool {
+    atom.text
+        .ends_with(|c: char| c.is_alphanumeric() || matches!(c, '_' | ')' | ']' | '}' | '"'))
+}
+
+/// Whether `ident` is a keyword that can come right before a parenthesized
+/// group without being called with it, as in `*const (u8, u16)`.
+fn is_keyword(ident: &str) -> bool {
+    matches!(
+        ident,
+        "mut" | "const" | "dyn" | "impl" | "as" | "in" | "where" | "unsafe"
+    )
+}
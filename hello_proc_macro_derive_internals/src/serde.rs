@@ -0,0 +1,86 @@
+//! The `#[serde(...)]` attributes read with `#[hello(serde_compat)]`.
+//!
+//! Only the options that change names on the wire are read, and only their
+//! serializing side. Anything else, malformed options included, is left for
+//! serde's own derive to use or report.
+
+use proc_macro2::TokenStream;
+use syn::meta::ParseNestedMeta;
+use syn::{Attribute, Expr, ExprLit, Lit, Token};
+
+use crate::case::RenameRule;
+
+/// The serde options of a type, variant or field.
+#[derive(Default)]
+pub struct Serde {
+    /// `rename = "..."` or `rename(serialize = "...")`
+    pub rename: Option<String>,
+    /// `rename_all = "..."` or `rename_all(serialize = "...")`
+    pub rename_all: Option<RenameRule>,
+    /// `skip` or `skip_serializing`
+    pub skip: bool,
+}
+
+impl Serde {
+    pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        let mut serde = Serde::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if let Some(name) = serialized(&meta)? {
+                        serde.rename = Some(name);
+                    }
+                } else if meta.path.is_ident("rename_all") {
+                    if let Some(rule) = serialized(&meta)? {
+                        serde.rename_all = RenameRule::from_str(&rule).ok();
+                    }
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                    serde.skip = true;
+                } else {
+                    ignore(&meta)?;
+                }
+                Ok(())
+            });
+        }
+        serde
+    }
+}
+
+/// The string of `name = "..."`, or of `serialize = "..."` in
+/// `name(serialize = "...", deserialize = "...")`.
+fn serialized(meta: &ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(Token![=]) {
+        return string(meta);
+    }
+    let mut name = None;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("serialize") {
+            name = string(&meta)?;
+        } else {
+            ignore(&meta)?;
+        }
+        Ok(())
+    })?;
+    Ok(name)
+}
+
+fn string(meta: &ParseNestedMeta) -> syn::Result<Option<String>> {
+    match meta.value()?.parse()? {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(Some(lit.value())),
+        _ => Ok(None),
+    }
+}
+
+/// Skips over the value of an option this crate doesn't read, if it has one.
+fn ignore(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        content.parse::<TokenStream>()?;
+    }
+    Ok(())
+}
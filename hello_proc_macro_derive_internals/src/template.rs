@@ -0,0 +1,224 @@
+//! Greeting templates, e.g. `#[hello(greeting = "Welcome aboard, {name}!")]`.
+//!
+//! Templates are checked while the derive runs and expand to a `concat!` of
+//! string literals, so a bad placeholder is a compile error rather than a
+//! surprise at runtime.
+
+use std::mem;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::LitStr;
+
+/// A parsed greeting template.
+pub struct Template {
+    pieces: Vec<Piece>,
+    /// Where the template was written, for errors found while expanding it.
+    span: Span,
+}
+
+enum Piece {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+enum Placeholder {
+    Name,
+    Module,
+    Kind,
+    Fields,
+    Variant,
+}
+
+/// What the placeholders of a template are filled in with.
+pub struct Context<'a> {
+    /// `{name}`: the type name, generics included.
+    pub name: &'a str,
+    /// Whether `{name}` is qualified with the module path of the type.
+    pub full_name: bool,
+    /// `{module}`: an expression for the module path of the type, usually
+    /// `::core::module_path!()`.
+    pub module: &'a TokenStream,
+    /// `{kind}`: `struct`, `enum` or `union`, or `None` for a type named by
+    /// its path alone, whose kind isn't known.
+    pub kind: Option<&'a str>,
+    /// `{fields}`: the field names separated by commas, or `None` where the
+    /// placeholder makes no sense.
+    pub fields: Option<&'a str>,
+    /// `{variant}`: the variant name, only set in the greeting of a variant.
+    pub variant: Option<&'a str>,
+}
+
+impl Template {
+    /// Parses `lit`, which uses `format!`-style braces: `{placeholder}` is
+    /// substituted, `{{` and `}}` are literal braces.
+    pub fn parse(lit: &LitStr) -> syn::Result<Self> {
+        let source = lit.value();
+        let span = lit.span();
+        let mut pieces = Vec::new();
+        let mut text = String::new();
+        let mut chars = source.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(ch) => name.push(ch),
+                            None => {
+                                return Err(syn::Error::new(
+                                    span,
+                                    "unclosed `{` in greeting, use `{{` for a literal brace",
+                                ))
+                            }
+                        }
+                    }
+                    let placeholder = match name.as_str() {
+                        "name" => Placeholder::Name,
+                        "module" => Placeholder::Module,
+                        "kind" => Placeholder::Kind,
+                        "fields" => Placeholder::Fields,
+                        "variant" => Placeholder::Variant,
+                        _ => {
+                            return Err(syn::Error::new(
+                                span,
+                                format!(
+                                    "unknown placeholder `{{{}}}` in greeting, expected one of \
+                                     `{{name}}`, `{{module}}`, `{{kind}}`, `{{fields}}` or \
+                                     `{{variant}}`",
+                                    name
+                                ),
+                            ))
+                        }
+                    };
+                    if !text.is_empty() {
+                        pieces.push(Piece::Text(mem::take(&mut text)));
+                    }
+                    pieces.push(Piece::Placeholder(placeholder));
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '}' => {
+                    return Err(syn::Error::new(
+                        span,
+                        "unmatched `}` in greeting, use `}}` for a literal brace",
+                    ))
+                }
+                ch => text.push(ch),
+            }
+        }
+        if !text.is_empty() {
+            pieces.push(Piece::Text(text));
+        }
+
+        Ok(Template { pieces, span })
+    }
+
+    /// `"Hello, this is Enum::Variant"`, naming the enum without its generics,
+    /// qualified with its module path if `full_name` is set.
+    pub fn default_for_variant(enum_name: &str, variant: &str, full_name: bool) -> Self {
+        let mut pieces = vec![Piece::Text("Hello, this is ".to_owned())];
+        if full_name {
+            pieces.push(Piece::Placeholder(Placeholder::Module));
+            pieces.push(Piece::Text("::".to_owned()));
+        }
+        pieces.push(Piece::Text(format!("{}::{}", enum_name, variant)));
+        Template {
+            pieces,
+            span: Span::call_site(),
+        }
+    }
+
+    /// Expands to a `concat!` producing the greeting as a `&'static str`.
+    pub fn expand(&self, cx: &Context) -> syn::Result<TokenStream> {
+        let parts = self.parts(cx)?;
+        Ok(quote!(::core::concat!(#(#parts),*)))
+    }
+
+    /// Like `expand`, for a `{module}` that `concat!` can't take because it
+    /// isn't a literal: if the greeting needs it, the pieces are joined by a
+    /// `const fn` instead.
+    pub fn expand_joined(&self, cx: &Context, krate: &syn::Path) -> syn::Result<TokenStream> {
+        let uses_module = self.pieces.iter().any(|piece| match piece {
+            Piece::Placeholder(Placeholder::Module) => true,
+            Piece::Placeholder(Placeholder::Name) => cx.full_name,
+            _ => false,
+        });
+        if !uses_module {
+            return self.expand(cx);
+        }
+        let parts = self.parts(cx)?;
+        Ok(quote!(#krate::__private::join!(&[#(#parts),*])))
+    }
+
+    fn parts(&self, cx: &Context) -> syn::Result<Vec<TokenStream>> {
+        let mut parts = Vec::new();
+        for piece in &self.pieces {
+            parts.push(match piece {
+                Piece::Text(text) => quote!(#text),
+                Piece::Placeholder(Placeholder::Name) => {
+                    let name = cx.name;
+                    if cx.full_name {
+                        let module = cx.module;
+                        quote!(#module, "::", #name)
+                    } else {
+                        quote!(#name)
+                    }
+                }
+                Piece::Placeholder(Placeholder::Module) => cx.module.clone(),
+                Piece::Placeholder(Placeholder::Kind) => match cx.kind {
+                    Some(kind) => quote!(#kind),
+                    None => {
+                        return Err(syn::Error::new(
+                            self.span,
+                            "`{kind}` can't be used in the greeting of a bare path",
+                        ))
+                    }
+                },
+                Piece::Placeholder(Placeholder::Fields) => match cx.fields {
+                    Some(fields) => quote!(#fields),
+                    None => {
+                        return Err(syn::Error::new(
+                            self.span,
+                            format!(
+                                "`{{fields}}` can't be used in the greeting of this {}",
+                                cx.kind.unwrap_or("type")
+                            ),
+                        ))
+                    }
+                },
+                Piece::Placeholder(Placeholder::Variant) => match cx.variant {
+                    Some(variant) => quote!(#variant),
+                    None => {
+                        return Err(syn::Error::new(
+                            self.span,
+                            "`{variant}` can only be used in the greeting of an enum variant",
+                        ))
+                    }
+                },
+            });
+        }
+        Ok(parts)
+    }
+}
+
+/// `"Hello, the name of your type is {name}"`
+impl Default for Template {
+    fn default() -> Self {
+        Template {
+            pieces: vec![
+                Piece::Text("Hello, the name of your type is ".to_owned()),
+                Piece::Placeholder(Placeholder::Name),
+            ],
+            span: Span::call_site(),
+        }
+    }
+}
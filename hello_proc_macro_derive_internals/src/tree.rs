@@ -0,0 +1,93 @@
+//! `write_tree_fields`, generated with `#[hello(tree)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Generics};
+
+use crate::attr;
+use crate::bound;
+use crate::field_names;
+use crate::render;
+
+/// Builds `write_tree_fields`, writing a line for every field of a struct or
+/// union, or for every variant of an enum followed by its fields. A type without
+/// either keeps the default, which writes nothing.
+pub fn expand(
+    ast: &DeriveInput,
+    attrs: &attr::Container,
+    krate: &syn::Path,
+) -> Option<TokenStream> {
+    let body = match &ast.data {
+        Data::Struct(data) if !data.fields.is_empty() => {
+            write_fields(&data.fields, &attrs.fields, &quote!(node))
+        }
+        Data::Enum(data) if !data.variants.is_empty() => {
+            let variants = data
+                .variants
+                .iter()
+                .zip(&attrs.variants)
+                .map(|(variant, attrs)| {
+                    let name = &attrs.name;
+                    if variant.fields.is_empty() {
+                        return quote! {
+                            node.write_variant(out, #name)?;
+                        };
+                    }
+                    let fields = write_fields(&variant.fields, &attrs.fields, &quote!(variant));
+                    quote! {
+                        let variant = node.write_variant(out, #name)?;
+                        #fields
+                    }
+                });
+            quote!(#(#variants)*)
+        }
+        Data::Union(data) if !data.fields.named.is_empty() => write_fields(
+            &Fields::Named(data.fields.clone()),
+            &attrs.fields,
+            &quote!(node),
+        ),
+        _ => return None,
+    };
+
+    Some(quote! {
+        fn write_tree_fields<__HelloWrite: ::core::fmt::Write + ?::core::marker::Sized>(
+            out: &mut __HelloWrite,
+            node: &#krate::TreeNode<'_>,
+        ) -> ::core::fmt::Result {
+            #body
+            ::core::result::Result::Ok(())
+        }
+    })
+}
+
+/// Writes a line for each of `fields` below `node`.
+fn write_fields(fields: &Fields, attrs: &[attr::Field], node: &TokenStream) -> TokenStream {
+    let lines = fields.iter().zip(field_names(attrs)).map(|(field, name)| {
+        let ty = &field.ty;
+        let rendered = render::render_type(ty);
+        quote! {
+            #node.write_field::<#ty, __HelloWrite>(out, #name, #rendered)?;
+        }
+    });
+    quote!(#(#lines)*)
+}
+
+/// `generics` with a `T: HelloProcMacro` bound on every type parameter used
+/// in a field, which the fields need to be written. Bounding the field types
+/// themselves would send the compiler in circles on recursive types.
+pub fn with_bounds(generics: &Generics, data: &Data, krate: &syn::Path) -> Generics {
+    bound::with_bound(generics, field_types(data), &quote!(#krate::HelloProcMacro))
+}
+
+/// The types of every field, including the fields of enum variants.
+fn field_types(data: &Data) -> Box<dyn Iterator<Item = &syn::Type> + '_> {
+    match data {
+        Data::Struct(data) => Box::new(data.fields.iter().map(|field| &field.ty)),
+        Data::Enum(data) => Box::new(
+            data.variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter().map(|field| &field.ty)),
+        ),
+        Data::Union(data) => Box::new(data.fields.named.iter().map(|field| &field.ty)),
+    }
+}
@@ -0,0 +1,212 @@
+//! Generation of `type_info()`, describing the deriving type.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, DeriveInput, Expr, ExprLit, Fields, GenericParam, Lit, Meta, MetaNameValue,
+    Token,
+};
+
+use crate::attr;
+use crate::render::{render_tokens, render_type};
+
+/// Builds `type_info()`, returning a `TypeInfo` kept in a static.
+pub fn expand(
+    ast: &DeriveInput,
+    attrs: &attr::Container,
+    krate: &syn::Path,
+) -> syn::Result<TokenStream> {
+    let name = ast.ident.unraw().to_string();
+    let visibility = render_tokens(ast.vis.to_token_stream());
+    let generics = ast
+        .generics
+        .params
+        .iter()
+        .map(|param| generic_param(param, krate));
+    let where_clause = match &ast.generics.where_clause {
+        Some(where_clause) => {
+            let predicates = where_clause
+                .predicates
+                .iter()
+                .map(|predicate| render_tokens(predicate.to_token_stream()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            quote!(::core::option::Option::Some(#predicates))
+        }
+        None => quote!(::core::option::Option::None),
+    };
+    let repr = repr_args(&ast.attrs)?;
+    let docs = doc_comment(&ast.attrs);
+
+    let (kind, fields, variants) = match &ast.data {
+        Data::Struct(data) => {
+            let style = style(&data.fields, krate);
+            (
+                quote!(#krate::Kind::Struct(#style)),
+                field_infos(&data.fields, &attrs.fields, krate),
+                quote!(&[]),
+            )
+        }
+        Data::Enum(data) => {
+            let variants = data
+                .variants
+                .iter()
+                .zip(&attrs.variants)
+                .map(|(variant, attrs)| {
+                    let name = &attrs.name;
+                    let style = style(&variant.fields, krate);
+                    let fields = field_infos(&variant.fields, &attrs.fields, krate);
+                    let discriminant = match &variant.discriminant {
+                        Some((_, expr)) => {
+                            let expr = render_tokens(expr.to_token_stream());
+                            quote!(::core::option::Option::Some(#expr))
+                        }
+                        None => quote!(::core::option::Option::None),
+                    };
+                    let docs = doc_comment(&variant.attrs);
+                    quote! {
+                        #krate::VariantInfo {
+                            name: #name,
+                            style: #style,
+                            fields: #fields,
+                            discriminant: #discriminant,
+                            docs: #docs,
+                        }
+                    }
+                });
+            (
+                quote!(#krate::Kind::Enum),
+                quote!(&[]),
+                quote!(&[#(#variants),*]),
+            )
+        }
+        Data::Union(data) => (
+            quote!(#krate::Kind::Union),
+            field_infos(&Fields::Named(data.fields.clone()), &attrs.fields, krate),
+            quote!(&[]),
+        ),
+    };
+
+    Ok(quote! {
+        fn type_info() -> &'static #krate::TypeInfo {
+            static INFO: #krate::TypeInfo = #krate::TypeInfo {
+                name: #name,
+                kind: #kind,
+                visibility: #visibility,
+                generics: &[#(#generics),*],
+                where_clause: #where_clause,
+                fields: #fields,
+                variants: #variants,
+                repr: &[#(#repr),*],
+                docs: #docs,
+            };
+            &INFO
+        }
+    })
+}
+
+fn style(fields: &Fields, krate: &syn::Path) -> TokenStream {
+    match fields {
+        Fields::Named(_) => quote!(#krate::Style::Named),
+        Fields::Unnamed(_) => quote!(#krate::Style::Tuple),
+        Fields::Unit => quote!(#krate::Style::Unit),
+    }
+}
+
+fn field_infos(fields: &Fields, attrs: &[attr::Field], krate: &syn::Path) -> TokenStream {
+    let names = crate::field_names(attrs);
+    let fields = fields.iter().zip(names).map(|(field, name)| {
+        let ty = render_type(&field.ty);
+        let visibility = render_tokens(field.vis.to_token_stream());
+        let docs = doc_comment(&field.attrs);
+        quote! {
+            #krate::FieldInfo {
+                name: #name,
+                ty: #ty,
+                visibility: #visibility,
+                docs: #docs,
+            }
+        }
+    });
+    quote!(&[#(#fields),*])
+}
+
+fn generic_param(param: &GenericParam, krate: &syn::Path) -> TokenStream {
+    let (name, kind, bounds, default) = match param {
+        GenericParam::Lifetime(param) => (
+            param.lifetime.to_string(),
+            quote!(#krate::GenericParamKind::Lifetime),
+            param.bounds.iter().map(|bound| bound.to_string()).collect(),
+            None,
+        ),
+        GenericParam::Type(param) => (
+            param.ident.to_string(),
+            quote!(#krate::GenericParamKind::Type),
+            param
+                .bounds
+                .iter()
+                .map(|bound| render_tokens(bound.to_token_stream()))
+                .collect::<Vec<_>>(),
+            param.default.as_ref().map(render_type),
+        ),
+        GenericParam::Const(param) => {
+            let ty = render_type(&param.ty);
+            (
+                param.ident.to_string(),
+                quote!(#krate::GenericParamKind::Const { ty: #ty }),
+                Vec::new(),
+                param
+                    .default
+                    .as_ref()
+                    .map(|default| render_tokens(default.to_token_stream())),
+            )
+        }
+    };
+    let default = match default {
+        Some(default) => quote!(::core::option::Option::Some(#default)),
+        None => quote!(::core::option::Option::None),
+    };
+    quote! {
+        #krate::GenericParamInfo {
+            name: #name,
+            kind: #kind,
+            bounds: &[#(#bounds),*],
+            default: #default,
+        }
+    }
+}
+
+/// The arguments of every `#[repr(...)]` in `attrs`.
+fn repr_args(attrs: &[Attribute]) -> syn::Result<Vec<String>> {
+    let mut repr = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let args = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        repr.extend(args.iter().map(|arg| render_tokens(arg.to_token_stream())));
+    }
+    Ok(repr)
+}
+
+/// The doc comment in `attrs`, with the space after each `///` removed.
+/// Docs that aren't string literals, e.g. `#[doc = include_str!(..)]`, are
+/// left out.
+fn doc_comment(attrs: &[Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("doc")) {
+        if let Meta::NameValue(MetaNameValue {
+            value: Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }),
+            ..
+        }) = &attr.meta
+        {
+            let line = lit.value();
+            lines.push(match line.strip_prefix(' ') {
+                Some(line) => line.to_owned(),
+                None => line,
+            });
+        }
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,132 @@
+//! Greetings with the values of the fields, generated with
+//! `#[hello(values)]`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, Fields, Generics, Member};
+
+use crate::attr;
+use crate::bound;
+
+/// The text written for a `#[hello(redact)]` field.
+const REDACTED: &str = "***";
+
+/// The value of a field, along with how the generated code reaches it.
+pub struct Value<'a> {
+    pub field: &'a syn::Field,
+    pub attrs: &'a attr::Field,
+    pub name: String,
+    pub member: Member,
+    /// The variable a `match` arm binds the field to.
+    pub binding: syn::Ident,
+}
+
+/// The fields of `fields` that are written, skipped ones left out.
+pub fn visible<'a>(fields: &'a Fields, attrs: &'a [attr::Field]) -> Vec<Value<'a>> {
+    fields
+        .iter()
+        .zip(attrs)
+        .enumerate()
+        .filter(|(_, (_, attrs))| !attrs.skip)
+        .map(|(index, (field, attrs))| Value {
+            field,
+            attrs,
+            name: attrs.name.clone(),
+            member: match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(index.into()),
+            },
+            binding: format_ident!("__field_{}", index),
+        })
+        .collect()
+}
+
+/// Writes ` { name: value, ... }` to `out` for `values`, reaching each value
+/// with `access`, or nothing if there are none.
+pub fn write(values: &[Value], access: impl Fn(&Value) -> TokenStream) -> TokenStream {
+    if values.is_empty() {
+        return TokenStream::new();
+    }
+
+    let mut writes = Vec::new();
+    let mut text = String::from(" { ");
+    for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+            text.push_str(", ");
+        }
+        text.push_str(&value.name);
+        text.push_str(": ");
+        if value.attrs.redact {
+            text.push_str(REDACTED);
+            continue;
+        }
+        writes.push(quote!(out.write_str(#text)?;));
+        text.clear();
+        let access = access(value);
+        writes.push(quote!(::core::write!(out, "{:?}", #access)?;));
+    }
+    text.push_str(" }");
+    writes.push(quote!(out.write_str(#text)?;));
+
+    quote!(#(#writes)*)
+}
+
+/// Builds `write_hello` and `hello` for a struct, greeting the type and then
+/// the values of its fields.
+pub fn impl_for_struct(fields: &Fields, attrs: &[attr::Field], krate: &syn::Path) -> TokenStream {
+    let values = write(&visible(fields, attrs), |value| {
+        let member = &value.member;
+        quote!(self.#member)
+    });
+    let hello = impl_hello(krate);
+
+    quote! {
+        fn write_hello<__HelloWrite: ::core::fmt::Write + ?::core::marker::Sized>(
+            &self,
+            out: &mut __HelloWrite,
+        ) -> ::core::fmt::Result {
+            out.write_str(<Self as #krate::HelloProcMacro>::GREETING)?;
+            #values
+            ::core::result::Result::Ok(())
+        }
+
+        #hello
+    }
+}
+
+/// Builds `hello` on top of `write_hello`, since the greeting is only known
+/// at runtime.
+pub fn impl_hello(krate: &syn::Path) -> TokenStream {
+    quote! {
+        #krate::__private::if_alloc! {
+            fn hello(&self) -> #krate::__private::Cow<'static, str> {
+                let mut hello = #krate::__private::String::new();
+                let _ = #krate::HelloProcMacro::write_hello(self, &mut hello);
+                #krate::__private::Cow::Owned(hello)
+            }
+        }
+    }
+}
+
+/// `generics` with a `T: Debug` bound on every type parameter used in a field
+/// whose value is written.
+pub fn with_bounds(generics: &Generics, data: &Data, attrs: &attr::Container) -> Generics {
+    let written = |fields: &'_ Fields, attrs: &'_ [attr::Field]| -> Vec<syn::Type> {
+        visible(fields, attrs)
+            .into_iter()
+            .filter(|value| !value.attrs.redact)
+            .map(|value| value.field.ty.clone())
+            .collect()
+    };
+    let types = match data {
+        Data::Struct(data) => written(&data.fields, &attrs.fields),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .zip(&attrs.variants)
+            .flat_map(|(variant, attrs)| written(&variant.fields, &attrs.fields))
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+    bound::with_bound(generics, types.iter(), &quote!(::core::fmt::Debug))
+}
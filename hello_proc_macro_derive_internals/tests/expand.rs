@@ -0,0 +1,417 @@
+use hello_proc_macro_derive_internals::{expand_derive, expand_remote};
+use quote::quote;
+use syn::{ImplItem, ImplItemConst, Item, ItemImpl, ItemMacro};
+
+fn expand_items(input: proc_macro2::TokenStream) -> Vec<Item> {
+    let output = expand_derive(input).expect("expansion failed");
+    syn::parse2::<syn::File>(output)
+        .expect("expansion is not a list of items")
+        .items
+}
+
+fn expand(input: proc_macro2::TokenStream) -> ItemImpl {
+    match expand_items(input).into_iter().next() {
+        Some(Item::Impl(item)) => item,
+        _ => panic!("expansion doesn't start with an impl"),
+    }
+}
+
+fn registration(input: proc_macro2::TokenStream) -> Option<ItemMacro> {
+    expand_items(input).into_iter().find_map(|item| match item {
+        Item::Macro(item) => Some(item),
+        _ => None,
+    })
+}
+
+fn expand_err(input: proc_macro2::TokenStream) -> String {
+    match expand_derive(input) {
+        Ok(output) => panic!("expected an error, got {}", output),
+        Err(err) => err.to_string(),
+    }
+}
+
+fn constant<'a>(item: &'a ItemImpl, name: &str) -> &'a ImplItemConst {
+    item.items
+        .iter()
+        .find_map(|item| match item {
+            ImplItem::Const(constant) if constant.ident == name => Some(constant),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("no `{}` in the expansion", name))
+}
+
+fn has_fn(item: &ItemImpl, name: &str) -> bool {
+    item.items
+        .iter()
+        .any(|item| matches!(item, ImplItem::Fn(f) if f.sig.ident == name))
+}
+
+#[test]
+fn implements_the_trait_by_its_absolute_path() {
+    let item = expand(quote!(
+        struct Mountain;
+    ));
+
+    let (_, path, _) = item.trait_.as_ref().unwrap();
+    assert_eq!(
+        quote!(#path).to_string(),
+        ":: hello_proc_macro :: HelloProcMacro"
+    );
+    let self_ty = &item.self_ty;
+    assert_eq!(quote!(#self_ty).to_string(), "Mountain");
+}
+
+#[test]
+fn builds_the_constants() {
+    let item = expand(quote! {
+        #[hello(greeting = "Welcome aboard, {name}!")]
+        struct Ship {
+            captain: String,
+        }
+    });
+
+    let type_name = &constant(&item, "TYPE_NAME").expr;
+    assert_eq!(quote!(#type_name).to_string(), "\"Ship\"");
+    let greeting = &constant(&item, "GREETING").expr;
+    assert_eq!(
+        quote!(#greeting).to_string(),
+        ":: core :: concat ! (\"Welcome aboard, \" , \"Ship\" , \"!\")"
+    );
+    let field_names = &constant(&item, "FIELD_NAMES").expr;
+    assert_eq!(quote!(#field_names).to_string(), "& [\"captain\"]");
+    assert!(has_fn(&item, "type_info"));
+    assert!(!has_fn(&item, "write_hello"));
+}
+
+#[test]
+fn keeps_generics_and_where_clauses() {
+    let item = expand(quote! {
+        struct Wrapper<'a, T: Clone = u8, const N: usize = 3>(&'a [T; N]) where T: Default;
+    });
+
+    let generics = &item.generics;
+    assert_eq!(
+        quote!(#generics).to_string(),
+        "< 'a , T : Clone , const N : usize >"
+    );
+    let where_clause = &item.generics.where_clause;
+    assert_eq!(quote!(#where_clause).to_string(), "where T : Default");
+    let self_ty = &item.self_ty;
+    assert_eq!(quote!(#self_ty).to_string(), "Wrapper < 'a , T , N >");
+}
+
+#[test]
+fn greets_enum_variants() {
+    let item = expand(quote! {
+        enum Direction {
+            North,
+        }
+    });
+
+    assert!(has_fn(&item, "write_hello"));
+}
+
+#[test]
+fn renames_fields_to_every_case() {
+    let cases = [
+        ("lowercase", "[\"max_altitude\"]"),
+        ("UPPERCASE", "[\"MAX_ALTITUDE\"]"),
+        ("PascalCase", "[\"MaxAltitude\"]"),
+        ("camelCase", "[\"maxAltitude\"]"),
+        ("snake_case", "[\"max_altitude\"]"),
+        ("SCREAMING_SNAKE_CASE", "[\"MAX_ALTITUDE\"]"),
+        ("kebab-case", "[\"max-altitude\"]"),
+        ("SCREAMING-KEBAB-CASE", "[\"MAX-ALTITUDE\"]"),
+    ];
+    for (case, expected) in cases {
+        let item = expand(quote! {
+            #[hello(rename_all = #case)]
+            struct Flight {
+                max_altitude: u32,
+            }
+        });
+        let field_names = &constant(&item, "FIELD_NAMES").expr;
+        assert_eq!(
+            quote!(#field_names).to_string(),
+            format!("& {}", expected),
+            "{}",
+            case
+        );
+    }
+}
+
+#[test]
+fn renames_variants_to_every_case() {
+    let cases = [
+        ("lowercase", "hardshell"),
+        ("UPPERCASE", "HARDSHELL"),
+        ("PascalCase", "HardShell"),
+        ("camelCase", "hardShell"),
+        ("snake_case", "hard_shell"),
+        ("SCREAMING_SNAKE_CASE", "HARD_SHELL"),
+        ("kebab-case", "hard-shell"),
+        ("SCREAMING-KEBAB-CASE", "HARD-SHELL"),
+    ];
+    for (case, expected) in cases {
+        let item = expand(quote! {
+            #[hello(rename_all = #case)]
+            enum Tent {
+                HardShell,
+            }
+        });
+        let write_hello = item
+            .items
+            .iter()
+            .find_map(|item| match item {
+                ImplItem::Fn(f) if f.sig.ident == "write_hello" => Some(f),
+                _ => None,
+            })
+            .unwrap();
+        let body = quote!(#write_hello).to_string();
+        assert!(
+            body.contains(&format!("\"Tent::{}\"", expected)),
+            "{}: {}",
+            case,
+            body
+        );
+    }
+}
+
+#[test]
+fn uses_the_crate_override() {
+    let item = expand(quote! {
+        #[hello(crate = "my_crate::hello")]
+        struct Mountain;
+    });
+
+    let (_, path, _) = item.trait_.as_ref().unwrap();
+    assert_eq!(
+        quote!(#path).to_string(),
+        "my_crate :: hello :: HelloProcMacro"
+    );
+}
+
+#[test]
+fn bounds_the_type_parameters_of_trees() {
+    let item = expand(quote! {
+        #[hello(tree)]
+        struct Pair<T, U, const N: usize> {
+            first: Option<T>,
+            rest: [u8; N],
+            marker: ::core::marker::PhantomData<fn() -> Self>,
+        }
+    });
+
+    let where_clause = &item.generics.where_clause;
+    assert_eq!(
+        quote!(#where_clause).to_string(),
+        quote!(where T: ::hello_proc_macro::HelloProcMacro).to_string()
+    );
+    assert!(has_fn(&item, "write_tree_fields"));
+
+    let item = expand(quote!(
+        struct Pair<T>(T);
+    ));
+    assert!(item.generics.where_clause.is_none());
+    assert!(!has_fn(&item, "write_tree_fields"));
+}
+
+#[test]
+fn registers_only_non_generic_types() {
+    let item = registration(quote!(
+        struct Mountain;
+    ))
+    .expect("no registration in the expansion");
+    let path = &item.mac.path;
+    assert_eq!(
+        quote!(#path).to_string(),
+        quote!(::hello_proc_macro::__private::if_registry).to_string()
+    );
+
+    assert!(registration(quote!(
+        struct Wrapper<T>(T);
+    ))
+    .is_none());
+}
+
+#[test]
+fn rejects_input_that_is_not_a_type() {
+    assert_eq!(
+        expand_err(quote!(
+            fn mountain() {}
+        )),
+        "expected one of: `struct`, `enum`, `union`"
+    );
+}
+
+#[test]
+fn describes_unions_without_reading_them() {
+    let item = expand(quote! {
+        union Bits {
+            int: u32,
+            float: f32,
+        }
+    });
+
+    let field_names = &constant(&item, "FIELD_NAMES").expr;
+    assert_eq!(quote!(#field_names).to_string(), "& [\"int\" , \"float\"]");
+    assert!(has_fn(&item, "type_info"));
+    assert!(!has_fn(&item, "hello"));
+    assert!(!has_fn(&item, "write_hello"));
+}
+
+#[test]
+fn rejects_values_on_unions() {
+    assert_eq!(
+        expand_err(quote! {
+            #[hello(values)]
+            union Bits { int: u32 }
+        }),
+        "`values` can't be used on unions, which don't know which of their fields is active"
+    );
+}
+
+#[test]
+fn rejects_bad_attributes() {
+    assert_eq!(
+        expand_err(quote! {
+            #[hello(shout)]
+            struct Mountain;
+        }),
+        "unknown `hello` option"
+    );
+    assert_eq!(
+        expand_err(quote! {
+            #[hello(greeting = "{nmae}")]
+            struct Mountain;
+        }),
+        "unknown placeholder `{nmae}` in greeting, expected one of `{name}`, `{module}`, \
+         `{kind}`, `{fields}` or `{variant}`"
+    );
+    assert_eq!(
+        expand_err(quote! {
+            #[hello(rename_all = "Title Case")]
+            struct Mountain;
+        }),
+        "unknown case \"Title Case\", expected one of \"lowercase\", \"UPPERCASE\", \
+         \"PascalCase\", \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \
+         \"kebab-case\", \"SCREAMING-KEBAB-CASE\""
+    );
+}
+
+#[test]
+fn expands_remote_mirrors_like_the_derive() {
+    let input = quote! {
+        #[hello(greeting = "Welcome to {name}")]
+        enum Direction {
+            North,
+            #[hello(greeting = "Heading {variant}")]
+            South(u8),
+        }
+    };
+
+    assert_eq!(
+        expand_remote(input.clone()).unwrap().to_string(),
+        expand_derive(input).unwrap().to_string()
+    );
+}
+
+#[test]
+fn expands_remote_paths() {
+    let output = expand_remote(quote! {
+        #[hello(greeting = "Welcome to {name}", crate = "::greetings")]
+        geo::Mountain
+    })
+    .unwrap();
+    let file: syn::File = syn::parse2(output).unwrap();
+    let Item::Impl(item) = &file.items[0] else {
+        panic!("expansion doesn't start with an impl");
+    };
+
+    let self_ty = &item.self_ty;
+    assert_eq!(
+        quote!(#self_ty).to_string(),
+        quote!(geo::Mountain).to_string()
+    );
+    let (_, path, _) = item.trait_.as_ref().unwrap();
+    assert_eq!(
+        quote!(#path).to_string(),
+        quote!(::greetings::HelloProcMacro).to_string()
+    );
+    let greeting = &constant(item, "GREETING").expr;
+    assert_eq!(
+        quote!(#greeting).to_string(),
+        quote!(::core::concat!("Welcome to ", "Mountain")).to_string()
+    );
+    let module_path = &constant(item, "MODULE_PATH").expr;
+    assert_eq!(
+        quote!(#module_path).to_string(),
+        quote!(::greetings::__private::module_path!("geo::Mountain")).to_string()
+    );
+    assert!(!has_fn(item, "type_info"));
+}
+
+#[test]
+fn rejects_bad_remote_input() {
+    let err = expand_remote(quote!(
+        #[hello(shout)]
+        Mountain
+    ))
+    .unwrap_err();
+    assert_eq!(err.to_string(), "unknown `hello` option");
+
+    let err = expand_remote(quote!(
+        #[hello(tree)]
+        Mountain
+    ))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`tree` needs the fields of the type, pass a mirror of its definition instead"
+    );
+
+    let err = expand_remote(quote!(
+        #[hello(values)]
+        Mountain
+    ))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`values` needs the fields of the type, pass a mirror of its definition instead"
+    );
+
+    let err = expand_remote(quote!(
+        #[hello(rename_all = "camelCase")]
+        Mountain
+    ))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`rename_all` needs the fields and variants of the type, pass a mirror of its \
+         definition instead"
+    );
+
+    let err = expand_remote(quote!(
+        #[hello(serde_compat)]
+        Mountain
+    ))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`serde_compat` needs the fields and variants of the type, pass a mirror of its \
+         definition instead"
+    );
+
+    let err = expand_remote(quote!(Mountain River)).unwrap_err();
+    assert_eq!(err.to_string(), "unexpected tokens after the type");
+
+    let err = expand_remote(quote!(
+        #[hello(greeting = "{fields}")]
+        Mountain
+    ))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`{fields}` can't be used in the greeting of this type"
+    );
+}
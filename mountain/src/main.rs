@@ -0,0 +1,60 @@
+use hello_proc_macro::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+struct Mountain;
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct River(u32);
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Lake {
+    depth: u32,
+}
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Wrapper<'a, T: Clone, const N: usize>
+where
+    T: Default,
+{
+    items: &'a [T; N],
+}
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Trail<'a, 'b: 'a, T: ?Sized + std::fmt::Debug, U = Vec<&'b str>, const LEN: usize = 3>
+where
+    U: IntoIterator,
+{
+    stops: &'a T,
+    markers: [&'b str; LEN],
+    rest: U,
+}
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Ridge<'a, T = &'a [u8], F = fn(&'a mut (u8, u16))> {
+    peaks: T,
+    climb: F,
+    path: &'a (),
+}
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Welcome aboard, {name}! A {kind} in {module} with {{{fields}}}.")]
+#[allow(dead_code)]
+struct Ship {
+    captain: String,
+    crew: u32,
+}
+
+fn main() {
+    Mountain::hello_proc_macro();
+    River::hello_proc_macro();
+    Lake::hello_proc_macro();
+    Wrapper::<u8, 2>::hello_proc_macro();
+    Trail::<str>::hello_proc_macro();
+    <Ridge>::hello_proc_macro();
+    Ship::hello_proc_macro();
+}
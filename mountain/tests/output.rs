@@ -0,0 +1,20 @@
+use std::process::Command;
+
+#[test]
+fn prints_the_name_of_every_derived_type() {
+    let output = Command::new(env!("CARGO_BIN_EXE_mountain"))
+        .output()
+        .expect("failed to run the mountain binary");
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Hello, the name of your type is Mountain\n\
+         Hello, the name of your type is River\n\
+         Hello, the name of your type is Lake\n\
+         Hello, the name of your type is Wrapper<'a, T: Clone, const N: usize>\n\
+         Hello, the name of your type is Trail<'a, 'b: 'a, T: ?Sized + std::fmt::Debug, U = Vec<&'b str>, const LEN: usize = 3>\n\
+         Hello, the name of your type is Ridge<'a, T = &'a [u8], F = fn(&'a mut (u8, u16))>\n\
+         Welcome aboard, Ship! A struct in mountain with {captain, crew}.\n"
+    );
+}
@@ -0,0 +1,125 @@
+//! An object-safe companion to `HelloProcMacro`.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::fmt;
+
+use crate::{HelloProcMacro, TypeInfo};
+
+/// The `&self` methods of [`HelloProcMacro`], usable through a trait object.
+///
+/// `HelloProcMacro` has constants and functions without a receiver, so there
+/// is no `dyn HelloProcMacro`. Every type implementing it implements
+/// `DynHello` as well, which lets values of different types share a
+/// collection:
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use hello_proc_macro::{DynHello, HelloProcMacro};
+///
+/// #[derive(HelloProcMacro)]
+/// struct Mountain;
+///
+/// #[derive(HelloProcMacro)]
+/// enum Direction {
+///     North,
+/// }
+///
+/// let values: Vec<Box<dyn DynHello>> = vec![Box::new(Mountain), Box::new(Direction::North)];
+/// let greetings: Vec<_> = values.iter().map(|value| value.dyn_hello()).collect();
+/// assert_eq!(
+///     greetings,
+///     [
+///         "Hello, the name of your type is Mountain",
+///         "Hello, this is Direction::North",
+///     ]
+/// );
+/// # }
+/// ```
+///
+/// The methods are prefixed with `dyn_` so that they don't clash with those
+/// of `HelloProcMacro` when both traits are in scope.
+pub trait DynHello {
+    /// [`HelloProcMacro::TYPE_NAME`] of the underlying type.
+    fn dyn_type_name(&self) -> &'static str;
+
+    /// [`HelloProcMacro::FULL_TYPE_NAME`] of the underlying type.
+    fn dyn_full_type_name(&self) -> &'static str;
+
+    /// [`HelloProcMacro::type_name`] of the underlying type, which names
+    /// the actual type of generic implementations, e.g. `"Vec<Mountain>"`.
+    #[cfg(feature = "alloc")]
+    fn dyn_composed_type_name(&self) -> Cow<'static, str>;
+
+    /// [`HelloProcMacro::full_type_name`] of the underlying type.
+    #[cfg(feature = "alloc")]
+    fn dyn_composed_full_type_name(&self) -> Cow<'static, str>;
+
+    /// [`HelloProcMacro::FIELD_NAMES`] of the underlying type.
+    fn dyn_field_names(&self) -> &'static [&'static str];
+
+    /// [`HelloProcMacro::type_info`] of the underlying type.
+    fn dyn_type_info(&self) -> &'static TypeInfo;
+
+    /// [`HelloProcMacro::greeting`] of the underlying type.
+    #[cfg(feature = "alloc")]
+    fn dyn_greeting(&self) -> Cow<'static, str>;
+
+    /// [`HelloProcMacro::hello`] of the value.
+    #[cfg(feature = "alloc")]
+    fn dyn_hello(&self) -> Cow<'static, str>;
+
+    /// [`HelloProcMacro::write_hello`] of the value.
+    fn dyn_write_hello(&self, out: &mut dyn fmt::Write) -> fmt::Result;
+
+    /// Prints the greeting of the value to stdout.
+    #[cfg(feature = "std")]
+    fn dyn_hello_proc_macro(&self);
+}
+
+impl<T: HelloProcMacro + ?Sized> DynHello for T {
+    fn dyn_type_name(&self) -> &'static str {
+        T::TYPE_NAME
+    }
+
+    fn dyn_full_type_name(&self) -> &'static str {
+        T::FULL_TYPE_NAME
+    }
+
+    #[cfg(feature = "alloc")]
+    fn dyn_composed_type_name(&self) -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn dyn_composed_full_type_name(&self) -> Cow<'static, str> {
+        T::full_type_name()
+    }
+
+    fn dyn_field_names(&self) -> &'static [&'static str] {
+        T::FIELD_NAMES
+    }
+
+    fn dyn_type_info(&self) -> &'static TypeInfo {
+        T::type_info()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn dyn_greeting(&self) -> Cow<'static, str> {
+        T::greeting()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn dyn_hello(&self) -> Cow<'static, str> {
+        self.hello()
+    }
+
+    fn dyn_write_hello(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.write_hello(out)
+    }
+
+    #[cfg(feature = "std")]
+    fn dyn_hello_proc_macro(&self) {
+        std::println!("{}", self.hello());
+    }
+}
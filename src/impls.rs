@@ -0,0 +1,293 @@
+//! Implementations for primitives, common std types, references, smart
+//! pointers, containers and tuples.
+//!
+//! For generic types, the constants use the declared form of the type, e.g.
+//! `"Vec<T>"`, since a constant can't be built from the names of generic
+//! parameters. The `type_name`, `full_type_name` and `greeting` functions
+//! compose the names of the actual types instead, e.g. `"Vec<Mountain>"`.
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, format, rc::Rc, string::String, vec::Vec};
+use core::fmt;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::{
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+    time::{Instant, SystemTime},
+};
+
+use crate::{HelloProcMacro, TreeNode};
+
+/// The default greeting, for a name only known at runtime.
+#[cfg(feature = "alloc")]
+fn greet(name: &str) -> Cow<'static, str> {
+    Cow::Owned(format!("Hello, the name of your type is {}", name))
+}
+
+/// The module part of `FULL_TYPE_NAME`, e.g. `"alloc::vec::"` for `Vec<T>`.
+#[cfg(feature = "alloc")]
+fn module_prefix<T: HelloProcMacro + ?Sized>() -> &'static str {
+    &T::FULL_TYPE_NAME[..T::FULL_TYPE_NAME.len() - T::TYPE_NAME.len()]
+}
+
+/// `FULL_TYPE_NAME` for `name` declared in `module`, if any.
+macro_rules! full_type_name {
+    ($name:literal) => {
+        $name
+    };
+    ($name:literal, $module:literal) => {
+        ::core::concat!($module, "::", $name)
+    };
+}
+
+/// Implements the trait for types that aren't generic, named as written and,
+/// outside of the primitives, qualified with the module they're public in.
+macro_rules! leaf {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl HelloProcMacro for $ty {
+                const TYPE_NAME: &'static str = ::core::stringify!($ty);
+                const GREETING: &'static str =
+                    ::core::concat!("Hello, the name of your type is ", ::core::stringify!($ty));
+            }
+        )+
+    };
+    ($module:literal: $($ty:ident),+ $(,)?) => {
+        $(
+            impl HelloProcMacro for $ty {
+                const TYPE_NAME: &'static str = ::core::stringify!($ty);
+                const MODULE_PATH: &'static str = $module;
+                const FULL_TYPE_NAME: &'static str =
+                    ::core::concat!($module, "::", ::core::stringify!($ty));
+                const GREETING: &'static str =
+                    ::core::concat!("Hello, the name of your type is ", ::core::stringify!($ty));
+            }
+        )+
+    };
+}
+
+leaf!(bool, char, str, ());
+leaf!(i8, i16, i32, i64, i128, isize);
+leaf!(u8, u16, u32, u64, u128, usize);
+leaf!(f32, f64);
+leaf!("core::time": Duration);
+leaf!("core::net": IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr);
+#[cfg(feature = "alloc")]
+leaf!("alloc::string": String);
+#[cfg(feature = "std")]
+leaf!("std::ffi": OsStr, OsString);
+#[cfg(feature = "std")]
+leaf!("std::path": Path, PathBuf);
+#[cfg(feature = "std")]
+leaf!("std::time": Instant, SystemTime);
+
+/// Greets the pointee rather than the pointer.
+macro_rules! forward_hello {
+    () => {
+        #[cfg(feature = "alloc")]
+        fn hello(&self) -> Cow<'static, str> {
+            (**self).hello()
+        }
+
+        fn write_hello<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+            (**self).write_hello(out)
+        }
+    };
+}
+
+/// Implements the trait for a type wrapping a single `T`, whose name is
+/// built by `compose` from the name of `T`. In a tree, the type stands for
+/// `T`: it shows the fields of `T`, and leads back to `T` if it's recursive.
+/// Pointers, marked `pointer`, also greet the pointee rather than themselves.
+macro_rules! wrapper {
+    (
+        $(#[$attr:meta])*
+        pointer impl[$($params:tt)*] $ty:ty { $($fields:tt)* }
+    ) => {
+        wrapper! {
+            @impl [$(#[$attr])*] [$($params)*] $ty { $($fields)* }
+            forward_hello!();
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        impl[$($params:tt)*] $ty:ty { $($fields:tt)* }
+    ) => {
+        wrapper! {
+            @impl [$(#[$attr])*] [$($params)*] $ty { $($fields)* }
+        }
+    };
+    (
+        @impl [$(#[$attr:meta])*] [$($params:tt)*] $ty:ty {
+            name: $name:literal,
+            $(module: $module:literal,)?
+            compose: |$inner:ident| $compose:expr,
+        }
+        $($methods:tt)*
+    ) => {
+        $(#[$attr])*
+        impl<$($params)*> HelloProcMacro for $ty {
+            const TYPE_NAME: &'static str = $name;
+            $(const MODULE_PATH: &'static str = $module;)?
+            const FULL_TYPE_NAME: &'static str = full_type_name!($name $(, $module)?);
+            const GREETING: &'static str =
+                ::core::concat!("Hello, the name of your type is ", $name);
+
+            #[cfg(feature = "alloc")]
+            fn type_name() -> Cow<'static, str> {
+                let $inner = T::type_name();
+                Cow::Owned($compose)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn full_type_name() -> Cow<'static, str> {
+                let $inner = T::full_type_name();
+                Cow::Owned(format!("{}{}", module_prefix::<Self>(), $compose))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn greeting() -> Cow<'static, str> {
+                greet(&Self::type_name())
+            }
+
+            fn tree_name() -> &'static str {
+                T::tree_name()
+            }
+
+            fn write_tree_fields<W: fmt::Write + ?Sized>(
+                out: &mut W,
+                node: &TreeNode<'_>,
+            ) -> fmt::Result {
+                T::write_tree_fields(out, node)
+            }
+
+            $($methods)*
+        }
+    };
+}
+
+wrapper! {
+    pointer impl['a, T: HelloProcMacro + ?Sized] &'a T {
+        name: "&T",
+        compose: |name| format!("&{}", name),
+    }
+}
+
+wrapper! {
+    #[cfg(feature = "alloc")]
+    pointer impl[T: HelloProcMacro + ?Sized] Box<T> {
+        name: "Box<T>",
+        module: "alloc::boxed",
+        compose: |name| format!("Box<{}>", name),
+    }
+}
+
+wrapper! {
+    #[cfg(feature = "alloc")]
+    pointer impl[T: HelloProcMacro + ?Sized] Rc<T> {
+        name: "Rc<T>",
+        module: "alloc::rc",
+        compose: |name| format!("Rc<{}>", name),
+    }
+}
+
+wrapper! {
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pointer impl[T: HelloProcMacro + ?Sized] Arc<T> {
+        name: "Arc<T>",
+        module: "alloc::sync",
+        compose: |name| format!("Arc<{}>", name),
+    }
+}
+
+wrapper! {
+    impl[T: HelloProcMacro] Option<T> {
+        name: "Option<T>",
+        module: "core::option",
+        compose: |name| format!("Option<{}>", name),
+    }
+}
+
+wrapper! {
+    #[cfg(feature = "alloc")]
+    impl[T: HelloProcMacro] Vec<T> {
+        name: "Vec<T>",
+        module: "alloc::vec",
+        compose: |name| format!("Vec<{}>", name),
+    }
+}
+
+wrapper! {
+    impl[T: HelloProcMacro, const N: usize] [T; N] {
+        name: "[T; N]",
+        compose: |name| format!("[{}; {}]", name, N),
+    }
+}
+
+wrapper! {
+    impl[T: HelloProcMacro] [T] {
+        name: "[T]",
+        compose: |name| format!("[{}]", name),
+    }
+}
+
+/// Implements the trait for a tuple of `T`s, naming it like the tuple type,
+/// e.g. `"(Mountain, River)"`.
+macro_rules! tuple {
+    ($name:literal; $($T:ident)+) => {
+        impl<$($T: HelloProcMacro),+> HelloProcMacro for ($($T,)+) {
+            const TYPE_NAME: &'static str = $name;
+            const GREETING: &'static str =
+                ::core::concat!("Hello, the name of your type is ", $name);
+
+            #[cfg(feature = "alloc")]
+            fn type_name() -> Cow<'static, str> {
+                tuple_name(&[$($T::type_name()),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn full_type_name() -> Cow<'static, str> {
+                tuple_name(&[$($T::full_type_name()),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn greeting() -> Cow<'static, str> {
+                greet(&Self::type_name())
+            }
+        }
+    };
+}
+
+/// The name of a tuple of the types called `names`.
+#[cfg(feature = "alloc")]
+fn tuple_name(names: &[Cow<'static, str>]) -> Cow<'static, str> {
+    let mut name = String::from("(");
+    for (index, element) in names.iter().enumerate() {
+        if index > 0 {
+            name.push_str(", ");
+        }
+        name.push_str(element);
+    }
+    if names.len() == 1 {
+        name.push(',');
+    }
+    name.push(')');
+    Cow::Owned(name)
+}
+
+tuple!("(A,)"; A);
+tuple!("(A, B)"; A B);
+tuple!("(A, B, C)"; A B C);
+tuple!("(A, B, C, D)"; A B C D);
+tuple!("(A, B, C, D, E)"; A B C D E);
+tuple!("(A, B, C, D, E, F)"; A B C D E F);
+tuple!("(A, B, C, D, E, F, G)"; A B C D E F G);
+tuple!("(A, B, C, D, E, F, G, H)"; A B C D E F G H);
+tuple!("(A, B, C, D, E, F, G, H, I)"; A B C D E F G H I);
+tuple!("(A, B, C, D, E, F, G, H, I, J)"; A B C D E F G H I J);
+tuple!("(A, B, C, D, E, F, G, H, I, J, K)"; A B C D E F G H I J K);
+tuple!("(A, B, C, D, E, F, G, H, I, J, K, L)"; A B C D E F G H I J K L);
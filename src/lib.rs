@@ -1,3 +1,366 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+mod dyn_hello;
+mod impls;
+mod path;
+#[cfg(feature = "registry")]
+pub mod registry;
+mod tree;
+mod type_info;
+
+pub use crate::dyn_hello::DynHello;
+pub use crate::tree::TreeNode;
+pub use crate::type_info::{
+    FieldInfo, GenericParamInfo, GenericParamKind, Kind, Style, TypeInfo, VariantInfo,
+};
+
+/// Derives [`HelloProcMacro`](trait@HelloProcMacro), enabled by the `derive`
+/// feature so that a single `use hello_proc_macro::HelloProcMacro;` brings
+/// both the trait and the derive into scope.
+#[cfg(feature = "derive")]
+pub use hello_proc_macro_derive::HelloProcMacro;
+
+/// Implements [`HelloProcMacro`](trait@HelloProcMacro) for a type that can't
+/// carry the derive, enabled by the `derive` feature.
+#[cfg(feature = "derive")]
+pub use hello_proc_macro_derive::hello_remote;
+
 pub trait HelloProcMacro {
-    fn hello_proc_macro ();
-}
\ No newline at end of file
+    /// The name of the type as it was declared, e.g. `"Mountain"` or
+    /// `"Wrapper<'a, T: Clone>"`.
+    const TYPE_NAME: &'static str;
+
+    /// The path of the module the type was declared in, e.g.
+    /// `"my_crate::geo"`. Empty unless provided by hand.
+    const MODULE_PATH: &'static str = "";
+
+    /// `TYPE_NAME` qualified with `MODULE_PATH`, e.g.
+    /// `"my_crate::geo::Mountain"`. Just `TYPE_NAME` unless provided by hand.
+    const FULL_TYPE_NAME: &'static str = Self::TYPE_NAME;
+
+    /// The greeting for the type, e.g. `"Hello, the name of your type is Mountain"`.
+    const GREETING: &'static str;
+
+    /// The names of the fields of a struct or union, in declaration order,
+    /// with tuple fields named by their index. Empty for unit structs and
+    /// enums.
+    ///
+    /// Derived names follow `#[hello(rename)]` and `rename_all`, and with
+    /// `#[hello(serde_compat)]` leave out the fields serde skips.
+    const FIELD_NAMES: &'static [&'static str] = &[];
+
+    /// The types of the fields named by `FIELD_NAMES`, as written in the
+    /// declaration.
+    const FIELD_TYPES: &'static [&'static str] = &[];
+
+    /// The number of fields in `FIELD_NAMES`.
+    const FIELD_COUNT: usize = Self::FIELD_NAMES.len();
+
+    /// A full description of the type. Implementations written by hand get
+    /// an [opaque](TypeInfo::opaque) one unless they provide their own.
+    fn type_info() -> &'static TypeInfo {
+        const { &TypeInfo::opaque(Self::TYPE_NAME) }
+    }
+
+    /// The name of this type, `TYPE_NAME` unless overridden. Generic
+    /// implementations such as the one for `Vec<T>` override it to name the
+    /// actual type, e.g. `"Vec<Mountain>"`.
+    #[cfg(feature = "alloc")]
+    fn type_name() -> Cow<'static, str> {
+        Cow::Borrowed(Self::TYPE_NAME)
+    }
+
+    /// The fully qualified name of this type, `FULL_TYPE_NAME` unless
+    /// overridden.
+    #[cfg(feature = "alloc")]
+    fn full_type_name() -> Cow<'static, str> {
+        Cow::Borrowed(Self::FULL_TYPE_NAME)
+    }
+
+    /// The greeting for this type, `GREETING` unless overridden.
+    #[cfg(feature = "alloc")]
+    fn greeting() -> Cow<'static, str> {
+        Cow::Borrowed(Self::GREETING)
+    }
+
+    /// Writes the greeting to `out`, without a trailing newline.
+    #[cfg(feature = "alloc")]
+    fn write_greeting<W: fmt::Write + ?Sized>(out: &mut W) -> fmt::Result {
+        out.write_str(&Self::greeting())
+    }
+
+    /// Writes the greeting to `out`, without a trailing newline.
+    #[cfg(not(feature = "alloc"))]
+    fn write_greeting<W: fmt::Write + ?Sized>(out: &mut W) -> fmt::Result {
+        out.write_str(Self::GREETING)
+    }
+
+    /// Writes the greeting to `out`, without a trailing newline.
+    #[cfg(feature = "std")]
+    fn write_greeting_io<W: io::Write + ?Sized>(out: &mut W) -> io::Result<()> {
+        out.write_all(Self::greeting().as_bytes())
+    }
+
+    /// The greeting for this value. Derived enums greet the active variant,
+    /// e.g. `"Hello, this is Direction::North"`; everything else defaults to
+    /// the greeting of the type.
+    #[cfg(feature = "alloc")]
+    fn hello(&self) -> Cow<'static, str> {
+        Self::greeting()
+    }
+
+    /// Writes the greeting for this value to `out`, without a trailing
+    /// newline.
+    #[cfg(feature = "alloc")]
+    fn write_hello<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        out.write_str(&self.hello())
+    }
+
+    /// Writes the greeting for this value to `out`, without a trailing
+    /// newline. Derived enums greet the active variant, e.g.
+    /// `"Hello, this is Direction::North"`; everything else defaults to the
+    /// greeting of the type.
+    #[cfg(not(feature = "alloc"))]
+    fn write_hello<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        Self::write_greeting(out)
+    }
+
+    /// Prints the greeting to stdout.
+    #[cfg(feature = "std")]
+    fn hello_proc_macro() {
+        std::println!("{}", Self::greeting());
+    }
+
+    /// What tells this type apart in [`write_tree`](HelloProcMacro::write_tree),
+    /// to stop at recursive types: [`core::any::type_name`] unless
+    /// overridden, which tells apart the instantiations of a generic type.
+    /// Pointers and containers return the one of the type they hold.
+    fn tree_name() -> &'static str {
+        core::any::type_name::<Self>()
+    }
+
+    /// Writes the fields of this type below `node`, in the tree written by
+    /// [`write_tree`](HelloProcMacro::write_tree). Writes nothing unless
+    /// derived with `#[hello(tree)]`; pointers and containers write the
+    /// fields of the type they hold.
+    fn write_tree_fields<W: fmt::Write + ?Sized>(
+        _out: &mut W,
+        _node: &TreeNode<'_>,
+    ) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Writes an indented tree of this type and the types of its fields,
+    /// recursively, one line each:
+    ///
+    /// ```text
+    /// Expedition
+    ///   leader: Person
+    ///     address: Address
+    ///   days: u32
+    /// ```
+    fn write_tree<W: fmt::Write + ?Sized>(out: &mut W) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        out.write_str(&Self::type_name())?;
+        #[cfg(not(feature = "alloc"))]
+        out.write_str(Self::TYPE_NAME)?;
+        out.write_char('\n')?;
+        Self::write_tree_fields(out, &TreeNode::root(Self::tree_name()))
+    }
+
+    /// Prints the tree written by [`write_tree`](HelloProcMacro::write_tree)
+    /// to stdout.
+    #[cfg(feature = "std")]
+    fn hello_tree() {
+        let mut tree = alloc::string::String::new();
+        Self::write_tree(&mut tree).expect("writing to a String can't fail");
+        std::print!("{}", tree);
+    }
+}
+
+/// Implements [`HelloProcMacro`](trait@HelloProcMacro) for a type that can't
+/// carry the derive, with the same constants the derive would generate: the
+/// type name, the module path of the type and either the default greeting or
+/// the given one.
+///
+/// ```
+/// use hello_proc_macro::{impl_hello, HelloProcMacro};
+///
+/// mod geo {
+///     pub struct Mountain;
+///     pub struct River<T>(pub T);
+/// }
+///
+/// impl_hello!(geo::Mountain);
+/// impl_hello!(geo::River<u8>, "Welcome to the River");
+///
+/// assert_eq!(geo::Mountain::GREETING, "Hello, the name of your type is Mountain");
+/// assert_eq!(geo::River::<u8>::TYPE_NAME, "River<u8>");
+/// assert_eq!(geo::River::<u8>::GREETING, "Welcome to the River");
+/// ```
+///
+/// The type is given by path, generic arguments included. Its module is the
+/// prefix of the path, resolved from the module the macro is used in:
+/// `crate::`, `self::` and `super::` work as usual, a leading `::` names
+/// another crate, and any other prefix is taken as a child module. Other
+/// types, such as references or tuples, are rejected.
+///
+/// The greeting is used as written: placeholders such as `{name}` are only
+/// filled in by the derive and `hello_remote!`. Like `hello_remote!` with a
+/// path, the implementation can't describe fields or variants, so
+/// `type_info` is opaque.
+#[macro_export]
+macro_rules! impl_hello {
+    ($ty:path $(,)?) => {
+        $crate::impl_hello!(
+            $ty,
+            $crate::__private::join!(&[
+                "Hello, the name of your type is ",
+                $crate::__private::type_name!(::core::stringify!($ty)),
+            ]),
+        );
+    };
+    ($ty:path, $greeting:expr $(,)?) => {
+        impl $crate::HelloProcMacro for $ty {
+            const TYPE_NAME: &'static str =
+                $crate::__private::type_name!(::core::stringify!($ty));
+            const MODULE_PATH: &'static str =
+                $crate::__private::module_path!(::core::stringify!($ty));
+            const FULL_TYPE_NAME: &'static str =
+                $crate::__private::full_type_name!(::core::stringify!($ty));
+            const GREETING: &'static str = $greeting;
+        }
+
+        $crate::__private::if_registry! {
+            const _: () = {
+                #[$crate::__private::linkme::distributed_slice($crate::__private::REGISTRY)]
+                #[linkme(crate = $crate::__private::linkme)]
+                static REGISTRATION: $crate::registry::Registration =
+                    $crate::registry::Registration::of::<$ty>();
+            };
+        }
+    };
+    ($($other:tt)*) => {
+        ::core::compile_error!(
+            "impl_hello! expects the path of a type, such as `geo::Mountain` or `geo::River<u8>`, optionally followed by a greeting"
+        );
+    };
+}
+
+/// Not public API, used by the code the derive generates.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::borrow::Cow;
+    #[cfg(feature = "alloc")]
+    pub use alloc::string::String;
+
+    #[cfg(feature = "registry")]
+    pub use crate::registry::REGISTRY;
+    #[cfg(feature = "registry")]
+    pub use linkme;
+
+    pub use crate::__hello_full_type_name as full_type_name;
+    pub use crate::__hello_if_alloc as if_alloc;
+    pub use crate::__hello_if_registry as if_registry;
+    pub use crate::__hello_join as join;
+    pub use crate::__hello_module_path as module_path;
+    pub use crate::__hello_type_name as type_name;
+    pub use crate::path::{
+        from_utf8, full_name_parts, join_bytes, joined_len, module_parts, type_name_parts,
+    };
+
+    /// Expands to its input only when the `alloc` feature is enabled, so the
+    /// derive can generate methods that only exist with `alloc`.
+    #[cfg(feature = "alloc")]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __hello_if_alloc {
+        ($($item:tt)*) => {
+            $($item)*
+        };
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __hello_if_alloc {
+        ($($item:tt)*) => {};
+    }
+
+    /// Expands to its input only when the `registry` feature is enabled, so
+    /// the derive can register types without requiring the feature.
+    #[cfg(feature = "registry")]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __hello_if_registry {
+        ($($item:tt)*) => {
+            $($item)*
+        };
+    }
+
+    #[cfg(not(feature = "registry"))]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __hello_if_registry {
+        ($($item:tt)*) => {};
+    }
+
+    /// Joins a `&[&str]` of constant strings into a `&'static str`, which
+    /// `concat!` can't do with anything but literals.
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __hello_join {
+        ($parts:expr) => {{
+            const PARTS: &[&str] = $parts;
+            const BYTES: [u8; $crate::__private::joined_len(PARTS)] =
+                $crate::__private::join_bytes(PARTS);
+            $crate::__private::from_utf8(&BYTES)
+        }};
+    }
+
+    /// The name of the type at `$path`, a type path as a string.
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __hello_type_name {
+        ($path:expr) => {
+            $crate::__private::join!(&$crate::__private::type_name_parts($path))
+        };
+    }
+
+    /// The module of the type at `$path`, a type path as a string, seen from
+    /// the module the macro is expanded in.
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __hello_module_path {
+        ($path:expr) => {
+            $crate::__private::join!(&$crate::__private::module_parts(
+                ::core::module_path!(),
+                $path,
+            ))
+        };
+    }
+
+    /// The module of the type at `$path`, followed by its name.
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __hello_full_type_name {
+        ($path:expr) => {
+            $crate::__private::join!(&$crate::__private::full_name_parts(
+                ::core::module_path!(),
+                $path,
+            ))
+        };
+    }
+}
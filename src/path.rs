@@ -0,0 +1,166 @@
+//! Names of types given by path, as in `impl_hello!(crate::geo::Lake)`,
+//! worked out while compiling.
+//!
+//! The module of such a type is the prefix of its path resolved from the
+//! module the macro is used in, which only `module_path!()` knows, so the
+//! names are put together by `const fn`s rather than `concat!`.
+
+/// The byte index where the last segment of `path` starts, skipping `::`
+/// inside generic arguments and the one of a turbofish, as in `River::<u8>`.
+const fn name_start(path: &str) -> usize {
+    let bytes = path.as_bytes();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'<' | b'(' | b'[' => depth += 1,
+            // `->` in `Fn(u8) -> u8` doesn't close anything.
+            b'>' if i > 0 && bytes[i - 1] == b'-' => {}
+            b'>' | b')' | b']' => depth -= 1,
+            b':' if depth == 0 && i + 1 < bytes.len() && bytes[i + 1] == b':' => {
+                if !starts_with(bytes, i + 2, b"<") {
+                    start = i + 2;
+                }
+                i += 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    start
+}
+
+/// The last segment of `path`, generic arguments included, as pieces to
+/// join: the name and its arguments, without the `::` of a turbofish, so
+/// `River::<u8>` is named `River<u8>` like the derive would. A raw name
+/// loses its `r#`, as in the derive.
+pub const fn type_name_parts(path: &'static str) -> [&'static str; 2] {
+    let mut segment = path.split_at(name_start(path)).1;
+    if starts_with(segment.as_bytes(), 0, b"r#") {
+        segment = segment.split_at(2).1;
+    }
+    let bytes = segment.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if starts_with(bytes, i, b"::<") {
+            return [segment.split_at(i).0, segment.split_at(i + 2).1];
+        }
+        if bytes[i] == b'<' {
+            break;
+        }
+        i += 1;
+    }
+    [segment, ""]
+}
+
+/// The module `path` names its type in, as pieces to join: the part of
+/// `module` the path starts from, a separator, and the rest of the path's
+/// prefix. `module` is the `module_path!()` where the path is written.
+pub const fn module_parts(module: &'static str, path: &'static str) -> [&'static str; 3] {
+    let prefix = path.split_at(name_start(path)).0.as_bytes();
+    let module_bytes = module.as_bytes();
+    let mut base = module_bytes.len();
+    let mut rest = 0;
+
+    if starts_with(prefix, 0, b"::") {
+        // `::other_crate::Type`
+        base = 0;
+        rest = 2;
+    } else if starts_with(prefix, 0, b"crate::") {
+        base = 0;
+        while base < module_bytes.len() && module_bytes[base] != b':' {
+            base += 1;
+        }
+        rest = 7;
+    } else {
+        // `self::super::Type` goes up from the current module.
+        if starts_with(prefix, 0, b"self::") {
+            rest = 6;
+        }
+        while starts_with(prefix, rest, b"super::") {
+            while base > 0 && module_bytes[base - 1] != b':' {
+                base -= 1;
+            }
+            base = base.saturating_sub(2);
+            rest += 7;
+        }
+    }
+
+    // The prefix keeps its trailing `::`, which isn't part of the module.
+    let rest = if rest + 2 <= prefix.len() {
+        path.split_at(prefix.len() - 2).0.split_at(rest).1
+    } else {
+        ""
+    };
+    let base = module.split_at(base).0;
+    let separator = if base.is_empty() || rest.is_empty() {
+        ""
+    } else {
+        "::"
+    };
+    [base, separator, rest]
+}
+
+/// `module_parts` followed by `::` and the type name.
+pub const fn full_name_parts(module: &'static str, path: &'static str) -> [&'static str; 6] {
+    let [base, separator, rest] = module_parts(module, path);
+    let name_separator = if base.is_empty() && rest.is_empty() {
+        ""
+    } else {
+        "::"
+    };
+    let [name, arguments] = type_name_parts(path);
+    [base, separator, rest, name_separator, name, arguments]
+}
+
+const fn starts_with(bytes: &[u8], at: usize, prefix: &[u8]) -> bool {
+    if at + prefix.len() > bytes.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[at + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// The length of `parts` joined together.
+pub const fn joined_len(parts: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// `parts` joined together, `N` being their `joined_len`.
+pub const fn join_bytes<const N: usize>(parts: &[&str]) -> [u8; N] {
+    let mut out = [0; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let bytes = parts[i].as_bytes();
+        let mut j = 0;
+        while j < bytes.len() {
+            out[len] = bytes[j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    out
+}
+
+/// `bytes` as a string, for the output of `join`, which joins strings.
+pub const fn from_utf8(bytes: &'static [u8]) -> &'static str {
+    match core::str::from_utf8(bytes) {
+        Ok(string) => string,
+        Err(_) => panic!("joined strings are UTF-8"),
+    }
+}
@@ -0,0 +1,114 @@
+//! A process-wide registry of every type that derives `HelloProcMacro`.
+//!
+//! Each derived type adds itself to the registry at link time, so there's no
+//! list to keep up to date: every type compiled into the binary shows up.
+//! Generic types aren't registered, since there's no single type to register.
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use hello_proc_macro::{registry, HelloProcMacro};
+//!
+//! #[derive(HelloProcMacro)]
+//! struct Mountain;
+//!
+//! let mountain = registry::find_by_name("Mountain").unwrap();
+//! assert_eq!(mountain.greeting(), "Hello, the name of your type is Mountain");
+//!
+//! for registration in registry::iter() {
+//!     println!("{}", registration.full_type_name());
+//! }
+//! # }
+//! ```
+
+use core::any::TypeId;
+use core::fmt;
+use std::borrow::Cow;
+
+use crate::{HelloProcMacro, TypeInfo};
+
+/// A registered type, as found by [`iter`], [`find_by_name`] and
+/// [`find_by_type_id`].
+pub struct Registration {
+    type_name: &'static str,
+    full_type_name: &'static str,
+    type_id: fn() -> TypeId,
+    greeting: fn() -> Cow<'static, str>,
+    type_info: fn() -> &'static TypeInfo,
+}
+
+impl Registration {
+    /// The registration of `T`. Not public API, used by the derive.
+    #[doc(hidden)]
+    pub const fn of<T: HelloProcMacro + 'static>() -> Self {
+        Registration {
+            type_name: T::TYPE_NAME,
+            full_type_name: T::FULL_TYPE_NAME,
+            type_id: TypeId::of::<T>,
+            greeting: T::greeting,
+            type_info: T::type_info,
+        }
+    }
+
+    /// The `TYPE_NAME` of the type, e.g. `"Mountain"`.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The `FULL_TYPE_NAME` of the type, e.g. `"my_crate::geo::Mountain"`.
+    pub fn full_type_name(&self) -> &'static str {
+        self.full_type_name
+    }
+
+    /// The `TypeId` of the type.
+    pub fn type_id(&self) -> TypeId {
+        (self.type_id)()
+    }
+
+    /// The greeting of the type, as returned by
+    /// [`HelloProcMacro::greeting`].
+    pub fn greeting(&self) -> Cow<'static, str> {
+        (self.greeting)()
+    }
+
+    /// The description of the type, as returned by
+    /// [`HelloProcMacro::type_info`].
+    pub fn type_info(&self) -> &'static TypeInfo {
+        (self.type_info)()
+    }
+}
+
+impl fmt::Debug for Registration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registration")
+            .field("full_type_name", &self.full_type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Every registered type, in no particular order.
+pub fn iter() -> impl Iterator<Item = &'static Registration> {
+    REGISTRY.iter()
+}
+
+/// The registered type called `name`, matched against the fully qualified
+/// name first and then the short name. Short names aren't unique across
+/// modules; if several types share one, any of them may be returned.
+pub fn find_by_name(name: &str) -> Option<&'static Registration> {
+    iter()
+        .find(|registration| registration.full_type_name == name)
+        .or_else(|| iter().find(|registration| registration.type_name == name))
+}
+
+/// The registered type whose `TypeId` is `id`.
+pub fn find_by_type_id(id: TypeId) -> Option<&'static Registration> {
+    iter().find(|registration| registration.type_id() == id)
+}
+
+/// The registration of `T`, if it's registered.
+pub fn find<T: 'static>() -> Option<&'static Registration> {
+    find_by_type_id(TypeId::of::<T>())
+}
+
+#[doc(hidden)]
+#[linkme::distributed_slice]
+pub static REGISTRY: [Registration];
@@ -0,0 +1,92 @@
+//! The tree written by `HelloProcMacro::write_tree`.
+
+use core::fmt;
+
+use crate::HelloProcMacro;
+
+/// A line of the tree written by [`HelloProcMacro::write_tree`], along with
+/// the lines above it, so that recursive types are written only once.
+///
+/// Types deriving with `#[hello(tree)]` write their fields below the node
+/// they're given with [`write_field`](TreeNode::write_field).
+#[derive(Debug, Clone, Copy)]
+pub struct TreeNode<'a> {
+    name: &'static str,
+    depth: usize,
+    parent: Option<&'a TreeNode<'a>>,
+}
+
+impl<'a> TreeNode<'a> {
+    /// The root of the tree, for the type whose [`tree_name`] is `name`.
+    ///
+    /// [`tree_name`]: HelloProcMacro::tree_name
+    pub fn root(name: &'static str) -> Self {
+        TreeNode {
+            name,
+            depth: 0,
+            parent: None,
+        }
+    }
+
+    /// How many lines are above this one, 0 for the root.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Whether the type whose [`tree_name`] is `name` is written on this line
+    /// or one above it.
+    ///
+    /// [`tree_name`]: HelloProcMacro::tree_name
+    pub fn contains(&self, name: &str) -> bool {
+        self.name == name || self.parent.is_some_and(|parent| parent.contains(name))
+    }
+
+    /// Writes a line for the field `label` of type `T`, declared as `ty`,
+    /// followed by the fields of `T`. If `T` is already written above, the
+    /// line is marked `(recursive)` instead.
+    pub fn write_field<T, W>(&self, out: &mut W, label: &str, ty: &str) -> fmt::Result
+    where
+        T: HelloProcMacro + ?Sized,
+        W: fmt::Write + ?Sized,
+    {
+        self.write_indent(out)?;
+        write!(out, "{}: {}", label, ty)?;
+
+        let name = T::tree_name();
+        if self.contains(name) {
+            return out.write_str(" (recursive)\n");
+        }
+        out.write_char('\n')?;
+        T::write_tree_fields(
+            out,
+            &TreeNode {
+                name,
+                depth: self.depth + 1,
+                parent: Some(self),
+            },
+        )
+    }
+
+    /// Writes a line for the enum variant `name`, returning the node to write
+    /// its fields below.
+    pub fn write_variant<W>(&self, out: &mut W, name: &str) -> Result<TreeNode<'_>, fmt::Error>
+    where
+        W: fmt::Write + ?Sized,
+    {
+        self.write_indent(out)?;
+        out.write_str(name)?;
+        out.write_char('\n')?;
+        Ok(TreeNode {
+            name: self.name,
+            depth: self.depth + 1,
+            parent: Some(self),
+        })
+    }
+
+    fn write_indent<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        for _ in 0..=self.depth {
+            out.write_str("  ")?;
+        }
+        Ok(())
+    }
+}
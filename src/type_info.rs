@@ -0,0 +1,119 @@
+//! A structured description of a type, filled in by the derive.
+
+/// Everything the derive knows about a type, as written in its declaration.
+///
+/// Derived types return one from [`HelloProcMacro::type_info`]. Types and
+/// bounds are kept as source text, e.g. `"Vec<&'a str>"`.
+///
+/// [`HelloProcMacro::type_info`]: crate::HelloProcMacro::type_info
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// The name of the type, without generics, e.g. `"Wrapper"`.
+    pub name: &'static str,
+    /// Whether the type is a struct, an enum or a union.
+    pub kind: Kind,
+    /// The visibility of the type, e.g. `"pub(crate)"`, or `""` if private.
+    pub visibility: &'static str,
+    /// The generic parameters of the type, in declaration order.
+    pub generics: &'static [GenericParamInfo],
+    /// The where clause of the type, without the `where` keyword.
+    pub where_clause: Option<&'static str>,
+    /// The fields of a struct or union. Empty for enums.
+    pub fields: &'static [FieldInfo],
+    /// The variants of an enum. Empty for structs and unions.
+    pub variants: &'static [VariantInfo],
+    /// The arguments of every `#[repr(...)]`, e.g. `["C", "align(8)"]`.
+    pub repr: &'static [&'static str],
+    /// The doc comment of the type, one line per `///` line.
+    pub docs: &'static str,
+}
+
+/// What kind of item a type was declared as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Struct(Style),
+    Enum,
+    Union,
+    /// A type implemented by hand, whose declaration the derive never saw.
+    Opaque,
+}
+
+/// How the fields of a struct or variant are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// `struct Lake { depth: u32 }`
+    Named,
+    /// `struct River(u32)`
+    Tuple,
+    /// `struct Mountain`
+    Unit,
+}
+
+/// A generic parameter, e.g. `T: Clone = u8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenericParamInfo {
+    /// The name of the parameter, e.g. `"'a"`, `"T"` or `"N"`.
+    pub name: &'static str,
+    pub kind: GenericParamKind,
+    /// The bounds of a lifetime or type parameter, e.g. `["Clone", "'a"]`.
+    pub bounds: &'static [&'static str],
+    /// The default of a type or const parameter.
+    pub default: Option<&'static str>,
+}
+
+/// What kind of generic parameter a [`GenericParamInfo`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenericParamKind {
+    Lifetime,
+    Type,
+    /// A const parameter of type `ty`, e.g. `"usize"`.
+    Const {
+        ty: &'static str,
+    },
+}
+
+/// A field of a struct, union or enum variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// The name of the field as the derive writes it, after
+    /// `#[hello(rename)]` or `rename_all`, or its index for tuple fields.
+    pub name: &'static str,
+    /// The type of the field, e.g. `"Option<u64>"`.
+    pub ty: &'static str,
+    /// The visibility of the field, e.g. `"pub"`, or `""` if private.
+    pub visibility: &'static str,
+    /// The doc comment of the field.
+    pub docs: &'static str,
+}
+
+/// A variant of an enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VariantInfo {
+    /// The name of the variant as the derive writes it, after
+    /// `#[hello(rename)]` or `rename_all`, e.g. `"North"`.
+    pub name: &'static str,
+    pub style: Style,
+    pub fields: &'static [FieldInfo],
+    /// The explicit discriminant of the variant, e.g. `"1 << 3"`.
+    pub discriminant: Option<&'static str>,
+    /// The doc comment of the variant.
+    pub docs: &'static str,
+}
+
+impl TypeInfo {
+    /// Describes a type known only by its name, for implementations written
+    /// by hand.
+    pub const fn opaque(name: &'static str) -> Self {
+        TypeInfo {
+            name,
+            kind: Kind::Opaque,
+            visibility: "",
+            generics: &[],
+            where_clause: None,
+            fields: &[],
+            variants: &[],
+            repr: &[],
+            docs: "",
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use hello_proc_macro::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+struct Mountain;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Welcome aboard, {name}!")]
+struct Ship;
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Wrapper<'a, T: Clone, const N: usize>(&'a [T; N]);
+
+const MOUNTAIN: &str = Mountain::TYPE_NAME;
+static GREETINGS: [&str; 2] = [Mountain::GREETING, Ship::GREETING];
+
+fn describe(name: &str) -> &'static str {
+    match name {
+        Mountain::TYPE_NAME => "a mountain",
+        Ship::TYPE_NAME => "a ship",
+        _ => "something else",
+    }
+}
+
+#[test]
+fn constants_are_usable_in_const_contexts() {
+    assert_eq!(MOUNTAIN, "Mountain");
+    assert_eq!(
+        GREETINGS,
+        [
+            "Hello, the name of your type is Mountain",
+            "Welcome aboard, Ship!"
+        ]
+    );
+}
+
+#[test]
+fn constants_are_usable_as_patterns() {
+    assert_eq!(describe("Mountain"), "a mountain");
+    assert_eq!(describe("Ship"), "a ship");
+    assert_eq!(describe("Lake"), "something else");
+}
+
+#[test]
+fn generic_types_use_the_declared_name() {
+    type Concrete<'a> = Wrapper<'a, u8, 4>;
+    assert_eq!(Concrete::TYPE_NAME, "Wrapper<'a, T: Clone, const N: usize>");
+    assert_eq!(
+        Concrete::GREETING,
+        "Hello, the name of your type is Wrapper<'a, T: Clone, const N: usize>"
+    );
+}
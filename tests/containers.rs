@@ -0,0 +1,129 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use hello_proc_macro::{DynHello, HelloProcMacro};
+
+mod geo {
+    use hello_proc_macro::HelloProcMacro;
+
+    #[derive(HelloProcMacro)]
+    pub struct Mountain;
+
+    #[derive(HelloProcMacro)]
+    pub struct River;
+
+    #[derive(HelloProcMacro)]
+    pub enum Direction {
+        North,
+    }
+}
+
+use geo::{Direction, Mountain, River};
+
+/// Only compiles if `T` implements the trait.
+fn name_of<T: HelloProcMacro + ?Sized>() -> String {
+    T::type_name().into_owned()
+}
+
+#[test]
+fn composes_the_names_of_wrappers_and_containers() {
+    assert_eq!(name_of::<&Mountain>(), "&Mountain");
+    assert_eq!(name_of::<Box<Mountain>>(), "Box<Mountain>");
+    assert_eq!(name_of::<Rc<Mountain>>(), "Rc<Mountain>");
+    assert_eq!(name_of::<Arc<Mountain>>(), "Arc<Mountain>");
+    assert_eq!(name_of::<Option<Mountain>>(), "Option<Mountain>");
+    assert_eq!(name_of::<Vec<Mountain>>(), "Vec<Mountain>");
+    assert_eq!(name_of::<[Mountain; 3]>(), "[Mountain; 3]");
+    assert_eq!(name_of::<[Mountain]>(), "[Mountain]");
+    assert_eq!(
+        name_of::<Vec<Option<Box<Mountain>>>>(),
+        "Vec<Option<Box<Mountain>>>"
+    );
+}
+
+#[test]
+fn composes_the_names_of_tuples() {
+    assert_eq!(name_of::<(Mountain,)>(), "(Mountain,)");
+    assert_eq!(name_of::<(Mountain, River)>(), "(Mountain, River)");
+    assert_eq!(
+        name_of::<(Mountain, [River; 2], Option<Direction>)>(),
+        "(Mountain, [River; 2], Option<Direction>)"
+    );
+}
+
+#[test]
+fn composes_full_names() {
+    assert_eq!(
+        Vec::<Mountain>::full_type_name(),
+        "alloc::vec::Vec<containers::geo::Mountain>"
+    );
+    assert_eq!(
+        <&Option<River>>::full_type_name(),
+        "&core::option::Option<containers::geo::River>"
+    );
+    assert_eq!(
+        <(Mountain, River)>::full_type_name(),
+        "(containers::geo::Mountain, containers::geo::River)"
+    );
+}
+
+#[test]
+fn keeps_the_declared_form_in_the_constants() {
+    assert_eq!(Vec::<Mountain>::TYPE_NAME, "Vec<T>");
+    assert_eq!(Vec::<Mountain>::FULL_TYPE_NAME, "alloc::vec::Vec<T>");
+    assert_eq!(Option::<Mountain>::MODULE_PATH, "core::option");
+    assert_eq!(<[Mountain; 3]>::TYPE_NAME, "[T; N]");
+    assert_eq!(<(Mountain, River)>::TYPE_NAME, "(A, B)");
+    assert_eq!(
+        <(Mountain, River)>::GREETING,
+        "Hello, the name of your type is (A, B)"
+    );
+}
+
+#[test]
+fn greets_the_composed_type() {
+    assert_eq!(
+        Vec::<Mountain>::greeting(),
+        "Hello, the name of your type is Vec<Mountain>"
+    );
+    assert_eq!(
+        Some(Mountain).hello(),
+        "Hello, the name of your type is Option<Mountain>"
+    );
+    assert_eq!(
+        (Mountain, River).hello(),
+        "Hello, the name of your type is (Mountain, River)"
+    );
+}
+
+#[test]
+fn pointers_greet_the_value_they_point_to() {
+    assert_eq!(
+        Box::new(Direction::North).hello(),
+        "Hello, this is Direction::North"
+    );
+    assert_eq!(
+        Rc::new(Direction::North).hello(),
+        "Hello, this is Direction::North"
+    );
+    assert_eq!(
+        <&Direction>::hello(&&Direction::North),
+        "Hello, this is Direction::North"
+    );
+
+    let mut out = String::new();
+    Arc::new(Direction::North).write_hello(&mut out).unwrap();
+    assert_eq!(out, "Hello, this is Direction::North");
+}
+
+#[test]
+fn trait_objects_see_the_composed_names() {
+    let values: Vec<Box<dyn DynHello>> =
+        vec![Box::new(vec![Mountain]), Box::new((Mountain, River))];
+    let names: Vec<_> = values
+        .iter()
+        .map(|value| value.dyn_composed_type_name())
+        .collect();
+    assert_eq!(names, ["Vec<Mountain>", "(Mountain, River)"]);
+    assert_eq!(values[0].dyn_type_name(), "Vec<T>");
+}
@@ -0,0 +1,76 @@
+use hello_proc_macro::{DynHello, HelloProcMacro, Kind, Style};
+
+#[derive(HelloProcMacro)]
+struct Mountain {
+    #[allow(dead_code)]
+    height: u32,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Flowing through {name}")]
+struct River;
+
+#[derive(HelloProcMacro)]
+enum Direction {
+    North,
+    #[hello(greeting = "Heading {variant}")]
+    South,
+}
+
+fn values() -> Vec<Box<dyn DynHello>> {
+    vec![
+        Box::new(Mountain { height: 8848 }),
+        Box::new(River),
+        Box::new(Direction::North),
+        Box::new(Direction::South),
+    ]
+}
+
+#[test]
+fn greets_each_value_through_a_trait_object() {
+    let greetings: Vec<_> = values().iter().map(|value| value.dyn_hello()).collect();
+    assert_eq!(
+        greetings,
+        [
+            "Hello, the name of your type is Mountain",
+            "Flowing through River",
+            "Hello, this is Direction::North",
+            "Heading South",
+        ]
+    );
+
+    let mut out = String::new();
+    for value in values() {
+        value.dyn_write_hello(&mut out).unwrap();
+        out.push('\n');
+    }
+    assert_eq!(out, greetings.join("\n") + "\n");
+}
+
+#[test]
+fn describes_the_underlying_type() {
+    let values = values();
+    let direction = &values[3];
+    assert_eq!(direction.dyn_type_name(), "Direction");
+    assert_eq!(direction.dyn_full_type_name(), "dyn_hello::Direction");
+    assert_eq!(direction.dyn_composed_type_name(), "Direction");
+    assert_eq!(
+        direction.dyn_composed_full_type_name(),
+        "dyn_hello::Direction"
+    );
+    assert_eq!(
+        direction.dyn_greeting(),
+        "Hello, the name of your type is Direction"
+    );
+    assert_eq!(direction.dyn_type_info().kind, Kind::Enum);
+
+    let mountain = &values[0];
+    assert_eq!(mountain.dyn_field_names(), ["height"]);
+    assert_eq!(mountain.dyn_type_info().kind, Kind::Struct(Style::Named));
+}
+
+#[test]
+fn leaves_the_static_methods_unambiguous() {
+    assert_eq!(River::greeting(), "Flowing through River");
+    assert_eq!(Direction::South.hello(), "Heading South");
+}
@@ -0,0 +1,95 @@
+use hello_proc_macro::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+enum Direction {
+    North,
+    #[hello(greeting = "Heading {variant}, away from the {name} pole")]
+    South,
+    #[allow(dead_code)]
+    Bearing(u16),
+    #[hello(greeting = "{variant} at {fields} on this {kind}")]
+    #[allow(dead_code)]
+    Coordinates {
+        lat: i32,
+        lon: i32,
+    },
+}
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "The {name} type")]
+#[allow(dead_code)]
+enum Signal<T: Copy> {
+    Idle,
+    Value(T),
+}
+
+#[derive(HelloProcMacro)]
+enum Never {}
+
+/// A parameter named `W` doesn't clash with the generated methods.
+#[derive(HelloProcMacro)]
+enum Either<V, W> {
+    Left(V),
+    Right(W),
+}
+
+#[test]
+fn greets_the_active_variant() {
+    assert_eq!(Direction::North.hello(), "Hello, this is Direction::North");
+    assert_eq!(
+        Direction::Bearing(90).hello(),
+        "Hello, this is Direction::Bearing"
+    );
+}
+
+#[test]
+fn variant_greeting_overrides_the_default() {
+    assert_eq!(
+        Direction::South.hello(),
+        "Heading South, away from the Direction pole"
+    );
+    assert_eq!(
+        Direction::Coordinates { lat: 51, lon: 0 }.hello(),
+        "Coordinates at lat, lon on this enum"
+    );
+}
+
+#[test]
+fn type_greeting_is_unaffected_by_variants() {
+    assert_eq!(
+        Direction::GREETING,
+        "Hello, the name of your type is Direction"
+    );
+    assert_eq!(Signal::<u8>::GREETING, "The Signal<T: Copy> type");
+}
+
+#[test]
+fn variant_greeting_names_generic_enums_without_parameters() {
+    assert_eq!(Signal::<u8>::Idle.hello(), "Hello, this is Signal::Idle");
+    assert_eq!(Signal::Value(1.5).hello(), "Hello, this is Signal::Value");
+}
+
+#[test]
+fn empty_enums_still_get_type_greeting() {
+    assert_eq!(Never::TYPE_NAME, "Never");
+}
+
+#[test]
+fn writes_the_active_variant() {
+    let mut out = String::new();
+    Direction::South.write_hello(&mut out).unwrap();
+    assert_eq!(out, "Heading South, away from the Direction pole");
+}
+
+#[test]
+fn generic_parameters_keep_their_names() {
+    assert_eq!(
+        Either::<u8, &str>::Left(1).hello(),
+        "Hello, this is Either::Left"
+    );
+    let mut out = String::new();
+    Either::<u8, &str>::Right("w")
+        .write_hello(&mut out)
+        .unwrap();
+    assert_eq!(out, "Hello, this is Either::Right");
+}
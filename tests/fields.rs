@@ -0,0 +1,83 @@
+use hello_proc_macro::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Expedition<'a> {
+    leader: &'a str,
+    members: Vec<String>,
+    budget: Option<u64>,
+}
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Point(i32, i32, [u8; 4]);
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Buffers<'a, T> {
+    slice: &'a [T],
+    scratch: &'a mut [u8],
+    raw: *const [u8],
+    pair: &'a (u8, u16),
+    maybe: Option<&'a [u8]>,
+}
+
+#[derive(HelloProcMacro)]
+struct Mountain;
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+enum Direction {
+    North,
+    Bearing(u16),
+}
+
+#[test]
+fn named_fields() {
+    assert_eq!(Expedition::FIELD_NAMES, ["leader", "members", "budget"]);
+    assert_eq!(
+        Expedition::FIELD_TYPES,
+        ["&'a str", "Vec<String>", "Option<u64>"]
+    );
+    assert_eq!(Expedition::FIELD_COUNT, 3);
+}
+
+#[test]
+fn references_keep_the_space_before_slices_and_tuples() {
+    assert_eq!(
+        Buffers::<u8>::FIELD_TYPES,
+        [
+            "&'a [T]",
+            "&'a mut [u8]",
+            "*const [u8]",
+            "&'a (u8, u16)",
+            "Option<&'a [u8]>",
+        ]
+    );
+}
+
+#[test]
+fn tuple_fields_are_named_by_index() {
+    assert_eq!(Point::FIELD_NAMES, ["0", "1", "2"]);
+    assert_eq!(Point::FIELD_TYPES, ["i32", "i32", "[u8; 4]"]);
+    assert_eq!(Point::FIELD_COUNT, 3);
+}
+
+#[test]
+fn unit_structs_and_enums_have_no_fields() {
+    assert!(Mountain::FIELD_NAMES.is_empty());
+    assert!(Mountain::FIELD_TYPES.is_empty());
+    assert_eq!(Mountain::FIELD_COUNT, 0);
+    assert_eq!(Direction::FIELD_COUNT, 0);
+}
+
+#[test]
+fn field_names_make_a_csv_header() {
+    const HEADER_LEN: usize = Expedition::FIELD_COUNT;
+    let header: [&str; HEADER_LEN] = [
+        Expedition::FIELD_NAMES[0],
+        Expedition::FIELD_NAMES[1],
+        Expedition::FIELD_NAMES[2],
+    ];
+    assert_eq!(header.join(","), "leader,members,budget");
+}
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+use hello_proc_macro::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+struct Mountain;
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "Welcome aboard, {name}!")]
+struct Ship;
+
+struct River;
+
+impl HelloProcMacro for River {
+    const TYPE_NAME: &'static str = "River";
+    const GREETING: &'static str = "Hello, the name of your type is River";
+}
+
+struct Lake;
+
+impl HelloProcMacro for Lake {
+    const TYPE_NAME: &'static str = "Lake";
+    const GREETING: &'static str = "Hello, the name of your type is Lake";
+
+    fn greeting() -> Cow<'static, str> {
+        Cow::Owned(format!("Hello, the name of your type is {}", "Lake"))
+    }
+}
+
+#[test]
+fn derived_greeting_is_borrowed() {
+    assert!(matches!(
+        Mountain::greeting(),
+        Cow::Borrowed("Hello, the name of your type is Mountain")
+    ));
+    assert_eq!(Ship::greeting(), "Welcome aboard, Ship!");
+}
+
+#[test]
+fn manual_impl_only_needs_the_constants() {
+    assert_eq!(River::greeting(), "Hello, the name of your type is River");
+
+    let mut out = String::new();
+    River::write_greeting(&mut out).unwrap();
+    assert_eq!(out, "Hello, the name of your type is River");
+}
+
+#[test]
+fn writers_use_an_overridden_greeting() {
+    assert!(matches!(Lake::greeting(), Cow::Owned(_)));
+
+    let mut out = String::new();
+    Lake::write_greeting(&mut out).unwrap();
+    assert_eq!(out, "Hello, the name of your type is Lake");
+}
+
+#[test]
+fn writes_to_fmt_write() {
+    let mut out = String::from("> ");
+    Mountain::write_greeting(&mut out).unwrap();
+    Ship::write_greeting(&mut out).unwrap();
+    assert_eq!(
+        out,
+        "> Hello, the name of your type is MountainWelcome aboard, Ship!"
+    );
+}
+
+#[test]
+fn writes_to_io_write() {
+    let mut out = Vec::new();
+    Mountain::write_greeting_io(&mut out).unwrap();
+    assert_eq!(out, b"Hello, the name of your type is Mountain");
+}
@@ -0,0 +1,156 @@
+use hello_proc_macro::{hello_remote, impl_hello, registry, HelloProcMacro, Kind};
+
+mod derived {
+    use hello_proc_macro::HelloProcMacro;
+
+    #[derive(HelloProcMacro)]
+    pub struct Mountain;
+
+    #[derive(HelloProcMacro)]
+    #[hello(greeting = "Welcome to the River")]
+    pub struct River;
+}
+
+mod by_hand {
+    use hello_proc_macro::impl_hello;
+
+    pub struct Mountain;
+    pub struct River;
+
+    impl_hello!(Mountain);
+    impl_hello!(River, "Welcome to the River");
+}
+
+mod remote {
+    pub struct Mountain;
+}
+
+mod generated {
+    pub struct Lake;
+
+    pub mod deep {
+        pub struct Pond;
+
+        hello_proc_macro::impl_hello!(super::Spring);
+        hello_proc_macro::impl_hello!(self::super::Well);
+    }
+
+    pub struct Well;
+
+    pub struct Spring;
+
+    pub struct Stream<T>(pub T);
+
+    pub struct Creek<T>(pub T);
+
+    #[allow(non_camel_case_types)]
+    pub struct r#match;
+}
+
+impl_hello!(crate::generated::Lake);
+impl_hello!(generated::deep::Pond, "A pond");
+impl_hello!(self::generated::Stream<u8>);
+impl_hello!(generated::Creek::<u8>);
+impl_hello!(generated::r#match);
+hello_remote!(remote::Mountain);
+
+#[test]
+fn matches_the_derive() {
+    assert_eq!(by_hand::Mountain::TYPE_NAME, derived::Mountain::TYPE_NAME);
+    assert_eq!(by_hand::Mountain::GREETING, derived::Mountain::GREETING);
+    assert_eq!(by_hand::River::GREETING, derived::River::GREETING);
+    assert_eq!(by_hand::Mountain::MODULE_PATH, "impl_hello::by_hand");
+    assert_eq!(
+        by_hand::Mountain::FULL_TYPE_NAME,
+        "impl_hello::by_hand::Mountain"
+    );
+}
+
+#[test]
+fn matches_hello_remote_with_a_path() {
+    assert_eq!(by_hand::Mountain::TYPE_NAME, remote::Mountain::TYPE_NAME);
+    assert_eq!(by_hand::Mountain::GREETING, remote::Mountain::GREETING);
+    assert_eq!(
+        by_hand::Mountain::type_info(),
+        remote::Mountain::type_info()
+    );
+    assert_eq!(by_hand::Mountain::type_info().kind, Kind::Opaque);
+}
+
+#[test]
+fn names_the_last_segment_of_a_path() {
+    assert_eq!(generated::Lake::TYPE_NAME, "Lake");
+    assert_eq!(generated::Lake::MODULE_PATH, "impl_hello::generated");
+    assert_eq!(
+        generated::Lake::FULL_TYPE_NAME,
+        "impl_hello::generated::Lake"
+    );
+    assert_eq!(
+        generated::Lake::greeting(),
+        "Hello, the name of your type is Lake"
+    );
+}
+
+#[test]
+fn registers_the_type() {
+    let lake = registry::find::<generated::Lake>().unwrap();
+    assert_eq!(lake.full_type_name(), "impl_hello::generated::Lake");
+}
+
+#[test]
+fn resolves_the_module_of_the_path() {
+    assert_eq!(
+        generated::deep::Pond::FULL_TYPE_NAME,
+        "impl_hello::generated::deep::Pond"
+    );
+    assert_eq!(generated::deep::Pond::GREETING, "A pond");
+    assert_eq!(
+        generated::Spring::FULL_TYPE_NAME,
+        "impl_hello::generated::Spring"
+    );
+    assert_eq!(generated::Well::MODULE_PATH, "impl_hello::generated");
+    assert_eq!(
+        generated::Well::FULL_TYPE_NAME,
+        "impl_hello::generated::Well"
+    );
+}
+
+#[test]
+fn keeps_generic_arguments() {
+    assert_eq!(generated::Stream::<u8>::TYPE_NAME, "Stream<u8>");
+    assert_eq!(
+        generated::Stream::<u8>::FULL_TYPE_NAME,
+        "impl_hello::generated::Stream<u8>"
+    );
+    assert_eq!(
+        generated::Stream::<u8>::GREETING,
+        "Hello, the name of your type is Stream<u8>"
+    );
+}
+
+#[test]
+fn drops_the_turbofish() {
+    assert_eq!(generated::Creek::<u8>::TYPE_NAME, "Creek<u8>");
+    assert_eq!(generated::Creek::<u8>::MODULE_PATH, "impl_hello::generated");
+    assert_eq!(
+        generated::Creek::<u8>::FULL_TYPE_NAME,
+        "impl_hello::generated::Creek<u8>"
+    );
+    assert_eq!(
+        generated::Creek::<u8>::GREETING,
+        "Hello, the name of your type is Creek<u8>"
+    );
+}
+
+#[test]
+fn unraws_the_name() {
+    assert_eq!(generated::r#match::TYPE_NAME, "match");
+    assert_eq!(
+        generated::r#match::FULL_TYPE_NAME,
+        "impl_hello::generated::match"
+    );
+    assert_eq!(
+        generated::r#match::GREETING,
+        "Hello, the name of your type is match"
+    );
+}
@@ -0,0 +1,88 @@
+use hello_proc_macro::HelloProcMacro;
+
+mod geo {
+    use hello_proc_macro::HelloProcMacro;
+
+    #[derive(HelloProcMacro)]
+    pub struct Mountain;
+
+    #[derive(HelloProcMacro)]
+    #[hello(name = "full")]
+    #[allow(dead_code)]
+    pub struct River<T>(pub T);
+
+    #[derive(HelloProcMacro)]
+    #[hello(name = "full", greeting = "Welcome to {name}")]
+    pub struct Lake;
+
+    #[derive(HelloProcMacro)]
+    #[hello(name = "full")]
+    pub enum Direction {
+        North,
+        #[hello(greeting = "Heading {variant} from {name}")]
+        South,
+    }
+}
+
+mod maps {
+    use hello_proc_macro::HelloProcMacro;
+
+    #[derive(HelloProcMacro)]
+    #[hello(name = "short")]
+    pub struct Mountain;
+}
+
+struct Manual;
+
+impl HelloProcMacro for Manual {
+    const TYPE_NAME: &'static str = "Manual";
+    const GREETING: &'static str = "Hello, the name of your type is Manual";
+}
+
+#[test]
+fn captures_the_module_path() {
+    assert_eq!(geo::Mountain::MODULE_PATH, "names::geo");
+    assert_eq!(geo::Mountain::FULL_TYPE_NAME, "names::geo::Mountain");
+    assert_eq!(geo::Mountain::full_type_name(), "names::geo::Mountain");
+}
+
+#[test]
+fn tells_apart_types_with_the_same_short_name() {
+    assert_eq!(geo::Mountain::TYPE_NAME, maps::Mountain::TYPE_NAME);
+    assert_ne!(
+        geo::Mountain::FULL_TYPE_NAME,
+        maps::Mountain::FULL_TYPE_NAME
+    );
+    assert_eq!(
+        maps::Mountain::GREETING,
+        "Hello, the name of your type is Mountain"
+    );
+}
+
+#[test]
+fn full_names_in_greetings() {
+    assert_eq!(
+        geo::River::<u8>::GREETING,
+        "Hello, the name of your type is names::geo::River<T>"
+    );
+    assert_eq!(geo::River::<u8>::TYPE_NAME, "River<T>");
+    assert_eq!(geo::Lake::GREETING, "Welcome to names::geo::Lake");
+}
+
+#[test]
+fn full_names_in_variant_greetings() {
+    assert_eq!(
+        geo::Direction::North.hello(),
+        "Hello, this is names::geo::Direction::North"
+    );
+    assert_eq!(
+        geo::Direction::South.hello(),
+        "Heading South from names::geo::Direction"
+    );
+}
+
+#[test]
+fn manual_impls_default_to_the_short_name() {
+    assert_eq!(Manual::MODULE_PATH, "");
+    assert_eq!(Manual::FULL_TYPE_NAME, "Manual");
+}
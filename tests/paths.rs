@@ -0,0 +1,116 @@
+//! Generated code only uses absolute paths, so deriving doesn't depend on
+//! what the deriving module has imported, shadowed or left out.
+
+mod without_the_trait_in_scope {
+    #[derive(hello_proc_macro_derive::HelloProcMacro)]
+    pub struct Mountain;
+
+    #[derive(hello_proc_macro_derive::HelloProcMacro)]
+    pub enum Direction {
+        North,
+    }
+}
+
+mod with_a_trait_of_the_same_name {
+    #[allow(dead_code)]
+    pub trait HelloProcMacro {}
+
+    #[derive(hello_proc_macro_derive::HelloProcMacro)]
+    pub struct Mountain;
+}
+
+#[no_implicit_prelude]
+mod without_the_prelude {
+    #![allow(dead_code)]
+
+    #[derive(::hello_proc_macro::HelloProcMacro)]
+    #[hello(greeting = "Hello from {module}")]
+    pub struct Mountain<T> {
+        pub height: T,
+    }
+
+    #[derive(::hello_proc_macro::HelloProcMacro)]
+    #[repr(u8)]
+    pub enum Direction {
+        North = 1,
+    }
+}
+
+mod with_shadowed_std_names {
+    #![allow(dead_code, non_camel_case_types, unused_macros)]
+
+    macro_rules! concat {
+        ($($tt:tt)*) => {
+            "shadowed"
+        };
+    }
+
+    pub enum Option {}
+    pub struct Some;
+    pub struct None;
+    pub struct std;
+    pub struct core;
+
+    #[derive(hello_proc_macro::HelloProcMacro)]
+    pub enum Direction {
+        North,
+    }
+}
+
+mod reexport {
+    pub use hello_proc_macro as hello;
+}
+
+mod through_a_reexport {
+    #[derive(crate::reexport::hello::HelloProcMacro)]
+    #[hello(crate = "crate::reexport::hello")]
+    pub struct Mountain;
+}
+
+use hello_proc_macro::{HelloProcMacro, Kind};
+
+#[test]
+fn derives_without_importing_the_trait() {
+    assert_eq!(without_the_trait_in_scope::Mountain::TYPE_NAME, "Mountain");
+    assert_eq!(
+        without_the_trait_in_scope::Direction::North.hello(),
+        "Hello, this is Direction::North"
+    );
+}
+
+#[test]
+fn derives_the_real_trait_despite_shadowing() {
+    assert_eq!(
+        with_a_trait_of_the_same_name::Mountain::GREETING,
+        "Hello, the name of your type is Mountain"
+    );
+}
+
+#[test]
+fn derives_without_the_prelude() {
+    assert_eq!(
+        without_the_prelude::Mountain::<u32>::GREETING,
+        "Hello from paths::without_the_prelude"
+    );
+    assert_eq!(
+        without_the_prelude::Direction::type_info().variants[0].discriminant,
+        Some("1")
+    );
+}
+
+#[test]
+fn derives_despite_shadowed_std_names() {
+    assert_eq!(
+        with_shadowed_std_names::Direction::North.hello(),
+        "Hello, this is Direction::North"
+    );
+    assert_eq!(
+        with_shadowed_std_names::Direction::type_info().kind,
+        Kind::Enum
+    );
+}
+
+#[test]
+fn derives_through_a_reexport() {
+    assert_eq!(through_a_reexport::Mountain::TYPE_NAME, "Mountain");
+}
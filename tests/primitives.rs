@@ -0,0 +1,80 @@
+use std::ffi::OsString;
+use std::net::Ipv4Addr;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use hello_proc_macro::HelloProcMacro;
+
+fn name_of<T: HelloProcMacro + ?Sized>() -> (String, String) {
+    (
+        T::type_name().into_owned(),
+        T::full_type_name().into_owned(),
+    )
+}
+
+#[test]
+fn names_primitives() {
+    assert_eq!(name_of::<bool>(), ("bool".into(), "bool".into()));
+    assert_eq!(name_of::<u32>(), ("u32".into(), "u32".into()));
+    assert_eq!(name_of::<i128>(), ("i128".into(), "i128".into()));
+    assert_eq!(name_of::<f64>(), ("f64".into(), "f64".into()));
+    assert_eq!(name_of::<char>(), ("char".into(), "char".into()));
+    assert_eq!(name_of::<str>(), ("str".into(), "str".into()));
+    assert_eq!(name_of::<()>(), ("()".into(), "()".into()));
+    assert_eq!(u8::GREETING, "Hello, the name of your type is u8");
+    assert_eq!(usize::MODULE_PATH, "");
+}
+
+#[test]
+fn qualifies_std_types_with_their_public_module() {
+    assert_eq!(
+        name_of::<String>(),
+        ("String".into(), "alloc::string::String".into())
+    );
+    assert_eq!(
+        name_of::<Duration>(),
+        ("Duration".into(), "core::time::Duration".into())
+    );
+    assert_eq!(
+        name_of::<PathBuf>(),
+        ("PathBuf".into(), "std::path::PathBuf".into())
+    );
+    assert_eq!(name_of::<Path>(), ("Path".into(), "std::path::Path".into()));
+    assert_eq!(OsString::FULL_TYPE_NAME, "std::ffi::OsString");
+    assert_eq!(Ipv4Addr::FULL_TYPE_NAME, "core::net::Ipv4Addr");
+    assert_eq!(Instant::MODULE_PATH, "std::time");
+    assert_eq!(
+        SystemTime::GREETING,
+        "Hello, the name of your type is SystemTime"
+    );
+}
+
+#[test]
+fn composes_with_containers() {
+    assert_eq!(<&str>::type_name(), "&str");
+    assert_eq!(Vec::<String>::type_name(), "Vec<String>");
+    assert_eq!(
+        <(u32, Option<PathBuf>)>::full_type_name(),
+        "(u32, core::option::Option<std::path::PathBuf>)"
+    );
+    assert_eq!(
+        <[u8; 4]>::greeting(),
+        "Hello, the name of your type is [u8; 4]"
+    );
+}
+
+#[test]
+fn works_in_generic_code() {
+    fn names<T: HelloProcMacro>(_: &[T]) -> &'static str {
+        T::TYPE_NAME
+    }
+
+    assert_eq!(names(&[1u64, 2]), "u64");
+    assert_eq!(names(&[true]), "bool");
+
+    assert_eq!(42u16.hello(), "Hello, the name of your type is u16");
+    assert_eq!(
+        "x".to_string().hello(),
+        "Hello, the name of your type is String"
+    );
+}
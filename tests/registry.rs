@@ -0,0 +1,65 @@
+use std::any::TypeId;
+
+use hello_proc_macro::{registry, HelloProcMacro, Kind};
+
+mod geo {
+    use hello_proc_macro::HelloProcMacro;
+
+    #[derive(HelloProcMacro)]
+    pub struct Mountain;
+
+    #[derive(HelloProcMacro)]
+    #[hello(greeting = "Welcome to {name}")]
+    pub enum Lake {}
+}
+
+mod maps {
+    use hello_proc_macro::HelloProcMacro;
+
+    #[derive(HelloProcMacro)]
+    pub struct Mountain;
+}
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct River<T>(T);
+
+#[test]
+fn registers_every_derived_type() {
+    let names: Vec<_> = registry::iter()
+        .map(|registration| registration.full_type_name())
+        .collect();
+
+    assert!(names.contains(&"registry::geo::Mountain"));
+    assert!(names.contains(&"registry::geo::Lake"));
+    assert!(names.contains(&"registry::maps::Mountain"));
+}
+
+#[test]
+fn leaves_out_generic_types() {
+    assert!(registry::find::<River<u8>>().is_none());
+    assert!(registry::iter().all(|registration| registration.type_name() != "River<T>"));
+}
+
+#[test]
+fn finds_types_by_name() {
+    let lake = registry::find_by_name("Lake").unwrap();
+    assert_eq!(lake.full_type_name(), "registry::geo::Lake");
+    assert_eq!(lake.greeting(), "Welcome to Lake");
+    assert_eq!(lake.type_info().kind, Kind::Enum);
+
+    let mountain = registry::find_by_name("registry::maps::Mountain").unwrap();
+    assert_eq!(mountain.type_id(), TypeId::of::<maps::Mountain>());
+
+    assert!(registry::find_by_name("Volcano").is_none());
+}
+
+#[test]
+fn finds_types_by_type_id() {
+    let mountain = registry::find_by_type_id(TypeId::of::<geo::Mountain>()).unwrap();
+    assert_eq!(mountain.full_type_name(), geo::Mountain::FULL_TYPE_NAME);
+    assert_eq!(mountain.greeting(), geo::Mountain::greeting());
+
+    assert!(registry::find::<maps::Mountain>().is_some());
+    assert!(registry::find::<String>().is_none());
+}
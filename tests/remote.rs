@@ -0,0 +1,150 @@
+use hello_proc_macro::{hello_remote, HelloProcMacro, Kind, Style};
+
+/// Types generated by a macro, which can't carry the derive.
+mod generated {
+    macro_rules! define {
+        ($($item:item)*) => {
+            $($item)*
+        };
+    }
+
+    define! {
+        #[allow(dead_code)]
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+
+        pub enum Signal {
+            Go,
+            Stop,
+        }
+
+        pub struct Token;
+
+        pub struct Flag<T>(pub T);
+
+        pub struct Match;
+    }
+
+    pub mod nested {
+        hello_proc_macro::hello_remote!(
+            #[hello(name = "full")]
+            self::super::Beacon
+        );
+    }
+
+    pub struct Beacon;
+}
+
+mod mirrors {
+    use super::generated::{Point, Signal};
+    use hello_proc_macro::hello_remote;
+
+    hello_remote! {
+        #[hello(greeting = "A {kind} with {fields}")]
+        pub struct Point {
+            pub x: i32,
+            pub y: i32,
+        }
+    }
+
+    hello_remote! {
+        enum Signal {
+            Go,
+            #[hello(greeting = "Halt at {variant}")]
+            Stop,
+        }
+    }
+}
+
+hello_remote!(
+    #[hello(name = "full")]
+    generated::Token
+);
+
+hello_remote!(
+    #[hello(greeting = "{name} from {module}")]
+    crate::generated::Flag<u8>
+);
+
+hello_remote!(
+    #[hello(name = "full")]
+    generated::Flag::<u16>
+);
+
+hello_remote!(generated::r#Match);
+
+#[test]
+fn mirrors_describe_the_type_in_scope() {
+    use generated::{Point, Signal};
+
+    assert_eq!(Point::GREETING, "A struct with x, y");
+    assert_eq!(Point::FIELD_NAMES, ["x", "y"]);
+    assert_eq!(Point::FIELD_TYPES, ["i32", "i32"]);
+    // A mirror can't tell where the type is declared, so it takes the
+    // module it is written in.
+    assert_eq!(Point::FULL_TYPE_NAME, "remote::mirrors::Point");
+    assert_eq!(Point::type_info().kind, Kind::Struct(Style::Named));
+
+    assert_eq!(Signal::Go.hello(), "Hello, this is Signal::Go");
+    assert_eq!(Signal::Stop.hello(), "Halt at Stop");
+}
+
+#[test]
+fn paths_get_names_and_a_greeting() {
+    use generated::Token;
+
+    assert_eq!(Token::TYPE_NAME, "Token");
+    assert_eq!(Token::MODULE_PATH, "remote::generated");
+    assert_eq!(Token::FULL_TYPE_NAME, "remote::generated::Token");
+    assert_eq!(
+        Token::GREETING,
+        "Hello, the name of your type is remote::generated::Token"
+    );
+    assert_eq!(Token::type_info().kind, Kind::Opaque);
+    assert!(Token::FIELD_NAMES.is_empty());
+}
+
+#[test]
+fn paths_keep_their_generic_arguments() {
+    use generated::Flag;
+
+    assert_eq!(Flag::<u8>::TYPE_NAME, "Flag<u8>");
+    assert_eq!(Flag::<u8>::FULL_TYPE_NAME, "remote::generated::Flag<u8>");
+    assert_eq!(Flag::<u8>::GREETING, "Flag<u8> from remote::generated");
+}
+
+#[test]
+fn paths_drop_the_turbofish() {
+    use generated::Flag;
+
+    assert_eq!(Flag::<u16>::TYPE_NAME, "Flag<u16>");
+    assert_eq!(Flag::<u16>::MODULE_PATH, "remote::generated");
+    assert_eq!(Flag::<u16>::FULL_TYPE_NAME, "remote::generated::Flag<u16>");
+    assert_eq!(
+        Flag::<u16>::GREETING,
+        "Hello, the name of your type is remote::generated::Flag<u16>"
+    );
+}
+
+#[test]
+fn paths_unraw_the_name() {
+    use generated::Match;
+
+    assert_eq!(Match::TYPE_NAME, "Match");
+    assert_eq!(Match::FULL_TYPE_NAME, "remote::generated::Match");
+    assert_eq!(Match::GREETING, "Hello, the name of your type is Match");
+}
+
+#[test]
+fn paths_go_up_from_self() {
+    use generated::Beacon;
+
+    assert_eq!(Beacon::MODULE_PATH, "remote::generated");
+    assert_eq!(Beacon::FULL_TYPE_NAME, "remote::generated::Beacon");
+    assert_eq!(
+        Beacon::GREETING,
+        "Hello, the name of your type is remote::generated::Beacon"
+    );
+}
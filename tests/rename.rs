@@ -0,0 +1,122 @@
+#![allow(dead_code)]
+
+use hello_proc_macro::{HelloProcMacro, Kind};
+
+#[derive(HelloProcMacro)]
+#[hello(rename_all = "camelCase", greeting = "{name} with {fields}")]
+struct Summit {
+    peak_height: u32,
+    #[hello(rename = "firstAscent")]
+    first_climbed: u16,
+    r#type: &'static str,
+}
+
+#[derive(Debug, HelloProcMacro)]
+#[hello(rename_all = "snake_case", values, tree)]
+enum Weather {
+    ClearSky,
+    #[hello(rename = "storm")]
+    Thunderstorm {
+        wind_speed: u8,
+    },
+    #[hello(rename_all = "SCREAMING_SNAKE_CASE")]
+    HeavySnow {
+        depth_cm: u16,
+    },
+    #[hello(greeting = "{variant} has {fields}")]
+    LightRain(u8),
+}
+
+#[derive(HelloProcMacro)]
+#[hello(rename_all = "kebab-case", values, tree)]
+struct Forecast {
+    high_temp: i8,
+    #[hello(rename = "outlook")]
+    weather: Weather,
+}
+
+#[derive(HelloProcMacro)]
+struct r#Type {
+    r#type: u8,
+}
+
+#[derive(HelloProcMacro)]
+enum r#Match {
+    r#Loop,
+}
+
+#[test]
+fn strips_raw_identifiers() {
+    assert_eq!(r#Type::TYPE_NAME, "Type");
+    assert_eq!(r#Type::FIELD_NAMES, &["type"]);
+    assert_eq!(r#Type::type_info().name, "Type");
+    assert_eq!(r#Match::r#Loop.hello(), "Hello, this is Match::Loop");
+}
+
+#[test]
+fn renames_field_lists() {
+    assert_eq!(
+        Summit::GREETING,
+        "Summit with peakHeight, firstAscent, type"
+    );
+    assert_eq!(Summit::FIELD_NAMES, &["peakHeight", "firstAscent", "type"]);
+}
+
+#[test]
+fn renames_variants() {
+    assert_eq!(
+        Weather::ClearSky.hello(),
+        "Hello, this is Weather::clear_sky"
+    );
+    assert_eq!(
+        Weather::Thunderstorm { wind_speed: 40 }.hello(),
+        "Hello, this is Weather::storm { wind_speed: 40 }"
+    );
+    assert_eq!(
+        Weather::HeavySnow { depth_cm: 30 }.hello(),
+        "Hello, this is Weather::heavy_snow { DEPTH_CM: 30 }"
+    );
+    assert_eq!(Weather::LightRain(2).hello(), "light_rain has 0 { 0: 2 }");
+}
+
+#[test]
+fn renames_field_values() {
+    let forecast = Forecast {
+        high_temp: 21,
+        weather: Weather::ClearSky,
+    };
+    assert_eq!(
+        forecast.hello(),
+        "Hello, the name of your type is Forecast { high-temp: 21, outlook: ClearSky }"
+    );
+}
+
+#[test]
+fn renames_trees() {
+    let mut out = String::new();
+    Forecast::write_tree(&mut out).unwrap();
+    assert_eq!(
+        out,
+        "\
+Forecast
+  high-temp: i8
+  outlook: Weather
+    clear_sky
+    storm
+      wind_speed: u8
+    heavy_snow
+      DEPTH_CM: u16
+    light_rain
+      0: u8
+"
+    );
+}
+
+#[test]
+fn renames_type_info() {
+    let info = Weather::type_info();
+    assert!(matches!(info.kind, Kind::Enum));
+    let names: Vec<_> = info.variants.iter().map(|variant| variant.name).collect();
+    assert_eq!(names, ["clear_sky", "storm", "heavy_snow", "light_rain"]);
+    assert_eq!(info.variants[2].fields[0].name, "DEPTH_CM");
+}
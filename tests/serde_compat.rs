@@ -0,0 +1,153 @@
+use hello_proc_macro::HelloProcMacro;
+use serde::Serialize;
+
+#[derive(Serialize, HelloProcMacro)]
+#[serde(rename_all = "camelCase")]
+#[hello(serde_compat, values, greeting = "{name} with {fields}")]
+struct Account {
+    user_name: String,
+    #[serde(rename = "mail")]
+    email_address: String,
+    #[serde(skip)]
+    #[allow(dead_code)]
+    session: u64,
+    #[serde(rename(serialize = "created", deserialize = "createdAt"))]
+    created_at: u32,
+}
+
+#[derive(Serialize, HelloProcMacro)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", deny_unknown_fields)]
+#[hello(serde_compat, values)]
+enum Status {
+    InReview,
+    #[serde(rename = "done")]
+    Approved,
+    #[serde(rename_all = "kebab-case")]
+    Rejected {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        review_note: Option<String>,
+    },
+}
+
+#[derive(Serialize, HelloProcMacro)]
+#[serde(rename_all = "snake_case")]
+#[hello(serde_compat)]
+enum Ev {
+    Started,
+    #[serde(skip)]
+    Skipped,
+    #[serde(skip_serializing)]
+    Hidden,
+}
+
+/// `#[hello(...)]` wins over `#[serde(...)]`.
+#[derive(Serialize, HelloProcMacro)]
+#[serde(rename_all = "PascalCase")]
+#[hello(serde_compat, rename_all = "kebab-case")]
+struct Override {
+    first_field: u8,
+    #[serde(rename = "Second")]
+    #[hello(rename = "two")]
+    second_field: u8,
+}
+
+#[derive(Serialize, HelloProcMacro)]
+#[serde(rename = "Wire")]
+#[hello(serde_compat)]
+struct Message {
+    #[allow(dead_code)]
+    body: String,
+}
+
+#[derive(Serialize, HelloProcMacro)]
+#[serde(rename(serialize = "light", deserialize = "Light"))]
+#[hello(serde_compat)]
+enum Lamp {
+    On,
+    #[hello(greeting = "{name} is off")]
+    Off,
+}
+
+/// Without `serde_compat`, serde's attributes are ignored.
+#[derive(Serialize, HelloProcMacro)]
+#[serde(rename_all = "camelCase")]
+struct Plain {
+    user_name: String,
+}
+
+fn account() -> Account {
+    Account {
+        user_name: "ada".into(),
+        email_address: "ada@example.com".into(),
+        session: 7,
+        created_at: 1815,
+    }
+}
+
+fn json_keys(value: &impl Serialize) -> Vec<String> {
+    match serde_json::to_value(value).unwrap() {
+        serde_json::Value::Object(map) => map.keys().cloned().collect(),
+        other => panic!("expected an object, got {}", other),
+    }
+}
+
+#[test]
+fn field_names_match_the_wire_format() {
+    let mut keys = json_keys(&account());
+    keys.sort();
+    let mut names = Account::FIELD_NAMES.to_vec();
+    names.sort();
+    assert_eq!(keys, names);
+    assert_eq!(Account::FIELD_NAMES, &["userName", "mail", "created"]);
+    assert_eq!(Account::FIELD_TYPES, &["String", "String", "u32"]);
+}
+
+#[test]
+fn greets_with_serde_names() {
+    assert_eq!(Account::GREETING, "Account with userName, mail, created");
+    assert_eq!(
+        account().hello(),
+        "Account with userName, mail, created \
+         { userName: \"ada\", mail: \"ada@example.com\", created: 1815 }"
+    );
+}
+
+#[test]
+fn renames_variants_like_serde() {
+    assert_eq!(
+        serde_json::to_string(&Status::InReview).unwrap(),
+        "\"IN_REVIEW\""
+    );
+    assert_eq!(Status::InReview.hello(), "Hello, this is Status::IN_REVIEW");
+    assert_eq!(Status::Approved.hello(), "Hello, this is Status::done");
+    assert_eq!(
+        Status::Rejected { review_note: None }.hello(),
+        "Hello, this is Status::REJECTED { review-note: None }"
+    );
+}
+
+#[test]
+fn greets_variants_serde_skips() {
+    assert!(serde_json::to_string(&Ev::Skipped).is_err());
+    assert_eq!(Ev::Started.hello(), "Hello, this is Ev::started");
+    assert_eq!(Ev::Skipped.hello(), "Hello, this is Ev::skipped");
+    assert_eq!(Ev::Hidden.hello(), "Hello, this is Ev::hidden");
+}
+
+#[test]
+fn greets_with_the_serde_name_of_the_type() {
+    assert_eq!(Message::GREETING, "Hello, the name of your type is Wire");
+    assert_eq!(Message::TYPE_NAME, "Message");
+    assert_eq!(Lamp::On.hello(), "Hello, this is light::On");
+    assert_eq!(Lamp::Off.hello(), "light is off");
+}
+
+#[test]
+fn hello_options_win() {
+    assert_eq!(Override::FIELD_NAMES, &["first-field", "two"]);
+}
+
+#[test]
+fn is_opt_in() {
+    assert_eq!(Plain::FIELD_NAMES, &["user_name"]);
+}
@@ -0,0 +1,182 @@
+#![allow(dead_code)]
+
+use hello_proc_macro::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(tree)]
+struct Expedition {
+    leader: Person,
+    members: Vec<Person>,
+    days: u32,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(tree)]
+struct Person {
+    name: String,
+    address: Option<Address>,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(tree)]
+struct Address {
+    street: String,
+    coordinates: (f64, f64),
+}
+
+#[derive(HelloProcMacro)]
+#[hello(tree)]
+struct Node {
+    value: u8,
+    next: Option<Box<Node>>,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(tree)]
+struct Pair<T> {
+    first: T,
+    second: Vec<T>,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(tree)]
+enum Route {
+    Direct,
+    Via(Address, u8),
+    Around { obstacle: Node },
+}
+
+/// A parameter named `W` doesn't clash with the generated methods.
+#[derive(HelloProcMacro)]
+#[hello(tree)]
+struct Wrapped<W> {
+    w: W,
+}
+
+/// Derived without `#[hello(tree)]`, so its fields aren't written.
+#[derive(HelloProcMacro)]
+struct Camp {
+    leader: Person,
+}
+
+fn tree<T: HelloProcMacro>() -> String {
+    let mut out = String::new();
+    T::write_tree(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn writes_nested_field_types() {
+    assert_eq!(
+        tree::<Expedition>(),
+        "\
+Expedition
+  leader: Person
+    name: String
+    address: Option<Address>
+      street: String
+      coordinates: (f64, f64)
+  members: Vec<Person>
+    name: String
+    address: Option<Address>
+      street: String
+      coordinates: (f64, f64)
+  days: u32
+"
+    );
+}
+
+#[test]
+fn stops_at_recursive_types() {
+    assert_eq!(
+        tree::<Node>(),
+        "\
+Node
+  value: u8
+  next: Option<Box<Node>> (recursive)
+"
+    );
+    assert_eq!(
+        tree::<Vec<Node>>(),
+        "\
+Vec<Node>
+  value: u8
+  next: Option<Box<Node>> (recursive)
+"
+    );
+}
+
+#[test]
+fn bounds_type_parameters() {
+    assert_eq!(
+        tree::<Pair<Person>>(),
+        "\
+Pair<T>
+  first: T
+    name: String
+    address: Option<Address>
+      street: String
+      coordinates: (f64, f64)
+  second: Vec<T>
+    name: String
+    address: Option<Address>
+      street: String
+      coordinates: (f64, f64)
+"
+    );
+}
+
+#[test]
+fn tells_instantiations_of_a_generic_type_apart() {
+    assert_eq!(
+        tree::<Pair<Pair<u32>>>(),
+        "\
+Pair<T>
+  first: T
+    first: T
+    second: Vec<T>
+  second: Vec<T>
+    first: T
+    second: Vec<T>
+"
+    );
+}
+
+#[test]
+fn keeps_parameters_named_w() {
+    assert_eq!(
+        tree::<Wrapped<Address>>(),
+        "\
+Wrapped<W>
+  w: W
+    street: String
+    coordinates: (f64, f64)
+"
+    );
+}
+
+#[test]
+fn writes_enum_variants() {
+    assert_eq!(
+        tree::<Route>(),
+        "\
+Route
+  Direct
+  Via
+    0: Address
+      street: String
+      coordinates: (f64, f64)
+    1: u8
+  Around
+    obstacle: Node
+      value: u8
+      next: Option<Box<Node>> (recursive)
+"
+    );
+}
+
+#[test]
+fn is_opt_in() {
+    assert_eq!(tree::<Camp>(), "Camp\n");
+    assert_eq!(tree::<u32>(), "u32\n");
+}
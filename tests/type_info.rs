@@ -0,0 +1,180 @@
+use hello_proc_macro::{
+    FieldInfo, GenericParamInfo, GenericParamKind, HelloProcMacro, Kind, Style, TypeInfo,
+    VariantInfo,
+};
+
+/// A trip into the mountains.
+///
+/// Led by one person.
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+pub struct Expedition<'a, T: Clone + 'a, const N: usize = 2>
+where
+    T: Default,
+{
+    /// Who is in charge.
+    pub leader: &'a str,
+    pub(crate) supplies: [T; N],
+}
+
+#[derive(HelloProcMacro)]
+#[repr(i8)]
+#[allow(dead_code)]
+enum Direction {
+    /// Up.
+    North = 1,
+    Bearing(u16) = 1 << 3,
+    South = -(4 + 1),
+    Coordinates {
+        lat: i32,
+        lon: i32,
+    },
+}
+
+#[derive(HelloProcMacro)]
+#[repr(C, align(8))]
+struct Mountain;
+
+#[derive(HelloProcMacro)]
+#[allow(dead_code)]
+struct Gen<'a, T, const N: usize, U = &'a [T]>(&'a [T; N], U)
+where
+    U: Into<&'a [T]>;
+
+struct River;
+
+impl HelloProcMacro for River {
+    const TYPE_NAME: &'static str = "River";
+    const GREETING: &'static str = "Hello, the name of your type is River";
+}
+
+#[test]
+fn describes_a_struct() {
+    assert_eq!(
+        *Expedition::<u8>::type_info(),
+        TypeInfo {
+            name: "Expedition",
+            kind: Kind::Struct(Style::Named),
+            visibility: "pub",
+            generics: &[
+                GenericParamInfo {
+                    name: "'a",
+                    kind: GenericParamKind::Lifetime,
+                    bounds: &[],
+                    default: None,
+                },
+                GenericParamInfo {
+                    name: "T",
+                    kind: GenericParamKind::Type,
+                    bounds: &["Clone", "'a"],
+                    default: None,
+                },
+                GenericParamInfo {
+                    name: "N",
+                    kind: GenericParamKind::Const { ty: "usize" },
+                    bounds: &[],
+                    default: Some("2"),
+                },
+            ],
+            where_clause: Some("T: Default"),
+            fields: &[
+                FieldInfo {
+                    name: "leader",
+                    ty: "&'a str",
+                    visibility: "pub",
+                    docs: "Who is in charge.",
+                },
+                FieldInfo {
+                    name: "supplies",
+                    ty: "[T; N]",
+                    visibility: "pub(crate)",
+                    docs: "",
+                },
+            ],
+            variants: &[],
+            repr: &[],
+            docs: "A trip into the mountains.\n\nLed by one person.",
+        }
+    );
+}
+
+#[test]
+fn describes_an_enum() {
+    let info = Direction::type_info();
+    assert_eq!(info.kind, Kind::Enum);
+    assert_eq!(info.visibility, "");
+    assert_eq!(info.repr, ["i8"]);
+    assert!(info.fields.is_empty());
+    assert_eq!(
+        info.variants,
+        [
+            VariantInfo {
+                name: "North",
+                style: Style::Unit,
+                fields: &[],
+                discriminant: Some("1"),
+                docs: "Up.",
+            },
+            VariantInfo {
+                name: "Bearing",
+                style: Style::Tuple,
+                fields: &[FieldInfo {
+                    name: "0",
+                    ty: "u16",
+                    visibility: "",
+                    docs: "",
+                }],
+                discriminant: Some("1 << 3"),
+                docs: "",
+            },
+            VariantInfo {
+                name: "South",
+                style: Style::Unit,
+                fields: &[],
+                discriminant: Some("-(4 + 1)"),
+                docs: "",
+            },
+            VariantInfo {
+                name: "Coordinates",
+                style: Style::Named,
+                fields: &[
+                    FieldInfo {
+                        name: "lat",
+                        ty: "i32",
+                        visibility: "",
+                        docs: "",
+                    },
+                    FieldInfo {
+                        name: "lon",
+                        ty: "i32",
+                        visibility: "",
+                        docs: "",
+                    },
+                ],
+                discriminant: None,
+                docs: "",
+            },
+        ]
+    );
+}
+
+#[test]
+fn describes_repr_and_unit_structs() {
+    let info = Mountain::type_info();
+    assert_eq!(info.kind, Kind::Struct(Style::Unit));
+    assert_eq!(info.repr, ["C", "align(8)"]);
+}
+
+#[test]
+fn describes_references_to_arrays_and_slices() {
+    let info = Gen::<u8, 2>::type_info();
+    assert_eq!(info.fields[0].ty, "&'a [T; N]");
+    assert_eq!(info.generics[3].default, Some("&'a [T]"));
+    assert_eq!(info.where_clause, Some("U: Into<&'a [T]>"));
+}
+
+#[test]
+fn manual_impls_are_opaque() {
+    assert_eq!(*River::type_info(), TypeInfo::opaque("River"));
+    assert_eq!(River::type_info().kind, Kind::Opaque);
+}
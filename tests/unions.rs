@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+
+use hello_proc_macro::{HelloProcMacro, Kind};
+
+#[derive(HelloProcMacro)]
+#[hello(greeting = "A {kind} of {fields}", tree)]
+#[repr(C)]
+union Bits {
+    int: u32,
+    #[hello(rename = "real")]
+    float: f32,
+}
+
+#[derive(HelloProcMacro)]
+union Slot<T: Copy> {
+    value: T,
+    empty: (),
+}
+
+#[test]
+fn greets_the_type() {
+    assert_eq!(Bits::GREETING, "A union of int, real");
+    assert_eq!(Bits { int: 1 }.hello(), "A union of int, real");
+    assert_eq!(Bits { float: 1.5 }.hello(), Bits::GREETING);
+    assert_eq!(
+        Slot::<u8> { empty: () }.hello(),
+        "Hello, the name of your type is Slot<T: Copy>"
+    );
+}
+
+#[test]
+fn describes_the_fields() {
+    assert_eq!(Bits::FIELD_NAMES, &["int", "real"]);
+    assert_eq!(Bits::FIELD_TYPES, &["u32", "f32"]);
+    assert_eq!(Slot::<u8>::FIELD_TYPES, &["T", "()"]);
+
+    let info = Bits::type_info();
+    assert!(matches!(info.kind, Kind::Union));
+    assert_eq!(info.repr, &["C"]);
+    assert_eq!(info.fields.len(), 2);
+}
+
+#[test]
+fn writes_the_tree() {
+    let mut out = String::new();
+    Bits::write_tree(&mut out).unwrap();
+    assert_eq!(out, "Bits\n  int: u32\n  real: f32\n");
+}
@@ -0,0 +1,97 @@
+use hello_proc_macro::HelloProcMacro;
+
+#[derive(HelloProcMacro)]
+#[hello(values)]
+struct Config {
+    host: String,
+    port: u16,
+    #[hello(redact)]
+    #[allow(dead_code)]
+    password: String,
+    #[hello(skip)]
+    #[allow(dead_code)]
+    cache: Vec<u8>,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(values, greeting = "Request")]
+struct Request<T>(u32, #[hello(redact)] T);
+
+/// Redacted and skipped fields aren't bounded by `Debug`.
+struct Secret;
+
+#[derive(HelloProcMacro)]
+#[hello(values)]
+struct Marker;
+
+/// A parameter named `W` doesn't clash with the generated methods.
+#[derive(HelloProcMacro)]
+#[hello(values)]
+struct Vals<W> {
+    w: W,
+}
+
+#[derive(HelloProcMacro)]
+#[hello(values)]
+enum Event {
+    Started,
+    Login {
+        user: String,
+        #[hello(redact)]
+        #[allow(dead_code)]
+        token: String,
+    },
+    #[hello(greeting = "{variant}!")]
+    Failed(i32, #[hello(skip)] Secret),
+}
+
+#[test]
+fn greets_with_field_values() {
+    let config = Config {
+        host: "localhost".into(),
+        port: 8080,
+        password: "hunter2".into(),
+        cache: vec![1, 2, 3],
+    };
+    assert_eq!(
+        config.hello(),
+        "Hello, the name of your type is Config { host: \"localhost\", port: 8080, password: *** }"
+    );
+
+    let mut out = String::new();
+    config.write_hello(&mut out).unwrap();
+    assert_eq!(out, config.hello());
+    assert_eq!(Config::greeting(), "Hello, the name of your type is Config");
+}
+
+#[test]
+fn names_tuple_fields_by_index() {
+    assert_eq!(Request(7, Secret).hello(), "Request { 0: 7, 1: *** }");
+}
+
+#[test]
+fn keeps_parameters_named_w() {
+    assert_eq!(
+        Vals { w: 3 }.hello(),
+        "Hello, the name of your type is Vals<W> { w: 3 }"
+    );
+}
+
+#[test]
+fn leaves_out_empty_values() {
+    assert_eq!(Marker.hello(), "Hello, the name of your type is Marker");
+}
+
+#[test]
+fn greets_variants_with_their_values() {
+    assert_eq!(Event::Started.hello(), "Hello, this is Event::Started");
+    assert_eq!(
+        Event::Login {
+            user: "ada".into(),
+            token: "abc".into(),
+        }
+        .hello(),
+        "Hello, this is Event::Login { user: \"ada\", token: *** }"
+    );
+    assert_eq!(Event::Failed(-1, Secret).hello(), "Failed! { 0: -1 }");
+}